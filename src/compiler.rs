@@ -1,9 +1,15 @@
 use crate::sys;
+use crate::Backend;
+use crate::CompilerOption;
 use crate::Context;
 use crate::Result;
+use std::borrow::Borrow;
+use std::ffi::c_char;
 use std::ffi::c_uint;
 use std::ffi::CStr;
+use std::marker::PhantomData;
 use std::mem::MaybeUninit;
+use std::ops::Deref;
 
 /// OpenGL Shading Language compiler & options
 #[cfg(feature = "glsl")]
@@ -20,6 +26,38 @@ pub mod hlsl;
 #[cfg_attr(docsrs, doc(cfg(feature = "msl")))]
 pub mod msl;
 
+/// Reflection of the resources used by a shader
+pub mod resources;
+
+/// Introspection of SPIR-V types
+pub mod types;
+
+/// Enumeration and selection of the module's entry points
+pub mod entry_points;
+
+/// Reflection and overriding of specialization constants
+pub mod specialization;
+
+/// Parallel compilation of many modules
+pub mod batch;
+
+/// Persistent cache of compilation results
+pub mod cache;
+
+mod capabilities;
+mod decorations;
+mod names;
+mod parsed_ir;
+
+#[doc(inline)]
+pub use batch::{
+    compile_batch, compile_batch_with_threads, CompileJob, CompileOutput, OptionValue,
+    ReflectedResource,
+};
+#[doc(inline)]
+pub use cache::{CacheKey, DiskCache};
+#[doc(inline)]
+pub use entry_points::{EntryPoint, WorkgroupDimension, WorkgroupSize};
 #[cfg(feature = "glsl")]
 #[doc(inline)]
 pub use glsl::GlslCompiler;
@@ -29,27 +67,25 @@ pub use hlsl::HlslCompiler;
 #[cfg(feature = "msl")]
 #[doc(inline)]
 pub use msl::MslCompiler;
+#[doc(inline)]
+pub use parsed_ir::ParsedIr;
+#[doc(inline)]
+pub use resources::{BuiltinResource, InterfaceVariables, Resource, ShaderResources};
+#[doc(inline)]
+pub use specialization::{ScalarValue, SpecializationConstant};
+#[doc(inline)]
+pub use types::{BlockLayout, MemberLayout, Type};
 
 /// A SPIRV-Cross compiler to an unkown target.
 pub trait Compiler<'a>: Sized {
     /// Sets the compiler's `option` property to the specified integer value.
-    fn set_uint(self, option: sys::spvc_compiler_option, value: c_uint) -> Result<Self>;
+    fn set_uint(self, option: CompilerOption, value: c_uint) -> Result<Self>;
 
     /// Sets the compiler's `option` property to the specified boolean value.
-    fn set_bool(self, option: sys::spvc_compiler_option, value: bool) -> Result<Self>;
-
-    /// Compiles the program with the specified options, returning a reference to the context's
-    /// resulting C string.
-    ///
-    /// This method is usefull if you want to avoid extra allocations,
-    /// or intend to pass the result to another C function.
-    fn raw_compile(self) -> Result<&'a CStr>;
+    fn set_bool(self, option: CompilerOption, value: bool) -> Result<Self>;
 
     fn force_temporary(self, force_temporary: bool) -> Result<Self> {
-        self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_FORCE_TEMPORARY,
-            force_temporary,
-        )
+        self.set_bool(CompilerOption::ForceTemporary, force_temporary)
     }
 
     fn flatten_multidimensional_arrays(
@@ -57,30 +93,21 @@ pub trait Compiler<'a>: Sized {
         flatten_multidimensional_arrays: bool,
     ) -> Result<Self> {
         self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_FLATTEN_MULTIDIMENSIONAL_ARRAYS,
+            CompilerOption::FlattenMultidimensionalArrays,
             flatten_multidimensional_arrays,
         )
     }
 
     fn fixup_depth_convention(self, fixup_depth_convention: bool) -> Result<Self> {
-        self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_FIXUP_DEPTH_CONVENTION,
-            fixup_depth_convention,
-        )
+        self.set_bool(CompilerOption::FixupDepthConvention, fixup_depth_convention)
     }
 
     fn flip_vertex_y(self, flip_vertex_y: bool) -> Result<Self> {
-        self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_FLIP_VERTEX_Y,
-            flip_vertex_y,
-        )
+        self.set_bool(CompilerOption::FlipVertexY, flip_vertex_y)
     }
 
     fn emit_line_directives(self, emit_line_directives: bool) -> Result<Self> {
-        self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_EMIT_LINE_DIRECTIVES,
-            emit_line_directives,
-        )
+        self.set_bool(CompilerOption::EmitLineDirectives, emit_line_directives)
     }
 
     fn enable_storage_image_qualifier_deduction(
@@ -88,7 +115,7 @@ pub trait Compiler<'a>: Sized {
         enable_storage_image_qualifier_deduction: bool,
     ) -> Result<Self> {
         self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_ENABLE_STORAGE_IMAGE_QUALIFIER_DEDUCTION,
+            CompilerOption::EnableStorageImageQualifierDeduction,
             enable_storage_image_qualifier_deduction,
         )
     }
@@ -98,88 +125,178 @@ pub trait Compiler<'a>: Sized {
         force_zero_initialized_variables: bool,
     ) -> Result<Self> {
         self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_FORCE_ZERO_INITIALIZED_VARIABLES,
+            CompilerOption::ForceZeroInitializedVariables,
             force_zero_initialized_variables,
         )
     }
 
+    fn relax_nan_checks(self, relax_nan_checks: bool) -> Result<Self> {
+        self.set_bool(CompilerOption::RelaxNanChecks, relax_nan_checks)
+    }
+
     /// Compiles the program with the specified options, returning a UTF-8 encoded copy of the result.
     ///
     /// This method makes use of `CStr::to_string_lossy`, so if the resulting source code returns invalid UTF-8, it will be replaced with the [`REPLACEMENT_CHARACTER`](std::char::REPLACEMENT_CHARACTER) (�).
-    fn compile(self) -> Result<String> {
-        let src = self.raw_compile()?;
-        return Ok(src.to_string_lossy().into_owned());
-    }
+    fn compile(self) -> Result<String>;
 }
 
 /// A SPIRV-Cross compiler to a manually specified target.
-pub struct GenericCompiler<'a> {
+///
+/// `C` is how the compiler holds its [`Context`]: borrowed by default, shared with the other compilers
+/// of a [`ParsedIr`], or owned by the compiler when created with [`GenericCompiler::new_owned`].
+pub struct GenericCompiler<'a, C = &'a mut Context> {
     pub compiler: sys::spvc_compiler,
     pub options: sys::spvc_compiler_options,
-    ctx: &'a mut Context,
+    ctx: ContextRef<C>,
+    lifetime: PhantomData<&'a ()>,
 }
 
+/// Context of a compiler, however the compiler holds it.
+struct ContextRef<C>(C);
+
+impl<C: Borrow<Context>> Deref for ContextRef<C> {
+    type Target = Context;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0.borrow()
+    }
+}
+
+/// Handle to a context that outlives the compiler for `'a`, so that its results can be borrowed as long.
+pub trait BorrowedContext<'a>: Borrow<Context> {}
+
+impl<'a> BorrowedContext<'a> for &'a mut Context {}
+
+impl<'a> BorrowedContext<'a> for &'a Context {}
+
 impl<'a> GenericCompiler<'a> {
     /// Creates a new [`GenericCompiler`]
-    pub fn new(ctx: &'a mut Context, backend: sys::spvc_backend, words: &[u32]) -> Result<Self> {
-        let mut parsed_ir = MaybeUninit::uninit();
-        let mut compiler = MaybeUninit::uninit();
-        let mut options = MaybeUninit::uninit();
+    pub fn new(ctx: &'a mut Context, backend: Backend, words: &[u32]) -> Result<Self> {
+        return ParsedIr::new(ctx, words)?.into_compiler(backend);
+    }
+}
 
+impl<'a, C: BorrowedContext<'a>> GenericCompiler<'a, C> {
+    /// Compiles the program with the specified options, returning a reference to the context's
+    /// resulting C string.
+    ///
+    /// This method is usefull if you want to avoid extra allocations,
+    /// or intend to pass the result to another C function.
+    pub fn raw_compile(mut self) -> Result<&'a CStr> {
+        // The result is allocated by the context, which outlives the compiler
         unsafe {
-            ctx.get_error(sys::spvc_context_parse_spirv(
-                ctx.inner,
-                words.as_ptr(),
-                words.len(),
-                parsed_ir.as_mut_ptr(),
-            ))?;
+            return Ok(CStr::from_ptr(self.compile_to_ptr()?));
+        }
+    }
+}
 
-            ctx.get_error(sys::spvc_context_create_compiler(
-                ctx.inner,
+impl GenericCompiler<'static, Context> {
+    /// Creates a new [`GenericCompiler`] that owns its context, keeping it alive as long as the compiler.
+    ///
+    /// Owned compilers don't borrow anything, so any number of them can be stored together.
+    /// Since the context is dropped along with the compiler, they have no `raw_compile`;
+    /// use [`GenericCompiler::raw_compile_mut`] or [`Compiler::compile`] instead.
+    ///
+    /// ```compile_fail,E0599
+    /// # use spirvcross::{compiler::GenericCompiler, Backend, Context};
+    /// # let words = [];
+    /// let compiler = GenericCompiler::new_owned(Context::new()?, Backend::None, &words)?;
+    /// let src = compiler.raw_compile()?;
+    /// # Ok::<(), spirvcross::Error>(())
+    /// ```
+    pub fn new_owned(ctx: Context, backend: Backend, words: &[u32]) -> Result<Self> {
+        unsafe {
+            let parsed_ir = parsed_ir::parse(&ctx, words)?;
+            return GenericCompiler::from_raw_parsed_ir(
+                ctx,
                 backend,
-                parsed_ir.assume_init(),
+                parsed_ir,
                 sys::spvc_capture_mode::SPVC_CAPTURE_MODE_TAKE_OWNERSHIP,
-                compiler.as_mut_ptr(),
-            ))?;
+            );
+        }
+    }
+}
 
-            ctx.get_error(sys::spvc_compiler_create_compiler_options(
-                compiler.assume_init_read(),
-                options.as_mut_ptr(),
-            ))?;
+impl<'a, C: Borrow<Context>> GenericCompiler<'a, C> {
+    unsafe fn from_raw_parsed_ir(
+        ctx: C,
+        backend: Backend,
+        parsed_ir: sys::spvc_parsed_ir,
+        capture_mode: sys::spvc_capture_mode,
+    ) -> Result<Self> {
+        let ctx = ContextRef(ctx);
+        let mut compiler = MaybeUninit::uninit();
+        let mut options = MaybeUninit::uninit();
 
-            return Ok(Self {
-                compiler: compiler.assume_init(),
-                options: options.assume_init(),
-                ctx,
-            });
-        }
+        ctx.get_error(sys::spvc_context_create_compiler(
+            ctx.inner,
+            backend.into(),
+            parsed_ir,
+            capture_mode,
+            compiler.as_mut_ptr(),
+        ))?;
+
+        ctx.get_error(sys::spvc_compiler_create_compiler_options(
+            compiler.assume_init_read(),
+            options.as_mut_ptr(),
+        ))?;
+
+        return Ok(Self {
+            compiler: compiler.assume_init(),
+            options: options.assume_init(),
+            ctx,
+            lifetime: PhantomData,
+        });
     }
 }
 
-impl<'a> Compiler<'a> for GenericCompiler<'a> {
-    fn set_uint(self, option: sys::spvc_compiler_option, value: c_uint) -> Result<Self> {
+impl<'a, C: Borrow<Context>> GenericCompiler<'a, C> {
+    /// Sets the compiler's `option` property to the specified integer value, without consuming the compiler.
+    pub fn set_uint_mut(&mut self, option: CompilerOption, value: c_uint) -> Result<()> {
         unsafe {
-            self.ctx.get_error(sys::spvc_compiler_options_set_uint(
+            return self.ctx.get_error(sys::spvc_compiler_options_set_uint(
                 self.options,
-                option,
+                option.into(),
                 value,
-            ))?;
+            ));
         }
-        return Ok(self);
     }
 
-    fn set_bool(self, option: sys::spvc_compiler_option, value: bool) -> Result<Self> {
+    /// Sets the compiler's `option` property to the specified boolean value, without consuming the compiler.
+    pub fn set_bool_mut(&mut self, option: CompilerOption, value: bool) -> Result<()> {
         unsafe {
-            self.ctx.get_error(sys::spvc_compiler_options_set_bool(
+            return self.ctx.get_error(sys::spvc_compiler_options_set_bool(
                 self.options,
-                option,
+                option.into(),
                 value as sys::spvc_bool,
-            ))?;
+            ));
+        }
+    }
+
+    /// Compiles the program with the current options, returning a reference to the context's
+    /// resulting C string.
+    ///
+    /// Unlike [`GenericCompiler::raw_compile`], the compiler can still be used afterwards, to reflect upon it
+    /// or to compile it again (for example, with another entry point or different options).
+    #[inline]
+    pub fn raw_compile_mut(&mut self) -> Result<&CStr> {
+        unsafe {
+            return Ok(CStr::from_ptr(self.compile_to_ptr()?));
         }
-        return Ok(self);
     }
 
-    fn raw_compile(self) -> Result<&'a CStr> {
+    /// Compiles the program with the current options, returning a UTF-8 encoded copy of the result.
+    ///
+    /// Unlike [`Compiler::compile`], the compiler can still be used afterwards, to reflect upon it
+    /// or to compile it again (for example, with another entry point or different options).
+    pub fn compile_mut(&mut self) -> Result<String> {
+        let src = self.raw_compile_mut()?;
+        return Ok(src.to_string_lossy().into_owned());
+    }
+
+    /// Compiles the program, returning a pointer to the result, which lives as long as the context's allocations.
+    fn compile_to_ptr(&mut self) -> Result<*const c_char> {
         let mut source = MaybeUninit::uninit();
         unsafe {
             self.ctx
@@ -193,11 +310,30 @@ impl<'a> Compiler<'a> for GenericCompiler<'a> {
                 source.as_mut_ptr(),
             ))?;
 
-            return Ok(CStr::from_ptr(source.assume_init()));
+            return Ok(source.assume_init());
         }
     }
 }
 
+impl<'a, C: Borrow<Context>> Compiler<'a> for GenericCompiler<'a, C> {
+    #[inline]
+    fn set_uint(mut self, option: CompilerOption, value: c_uint) -> Result<Self> {
+        self.set_uint_mut(option, value)?;
+        return Ok(self);
+    }
+
+    #[inline]
+    fn set_bool(mut self, option: CompilerOption, value: bool) -> Result<Self> {
+        self.set_bool_mut(option, value)?;
+        return Ok(self);
+    }
+
+    #[inline]
+    fn compile(mut self) -> Result<String> {
+        self.compile_mut()
+    }
+}
+
 impl sys::spvc_compiler_option {
     pub fn is_common(self) -> bool {
         return (self as u32) & sys::SPVC_COMPILER_OPTION_COMMON_BIT != 0;
@@ -216,5 +352,27 @@ impl sys::spvc_compiler_option {
     }
 }
 
-unsafe impl<'a> Send for GenericCompiler<'a> {}
-unsafe impl<'a> Sync for GenericCompiler<'a> {}
+impl CompilerOption {
+    #[inline]
+    pub fn is_common(self) -> bool {
+        return sys::spvc_compiler_option::from(self).is_common();
+    }
+
+    #[inline]
+    pub fn is_glsl(self) -> bool {
+        return sys::spvc_compiler_option::from(self).is_glsl();
+    }
+
+    #[inline]
+    pub fn is_hlsl(self) -> bool {
+        return sys::spvc_compiler_option::from(self).is_hlsl();
+    }
+
+    #[inline]
+    pub fn is_msl(self) -> bool {
+        return sys::spvc_compiler_option::from(self).is_msl();
+    }
+}
+
+// The compiler's handles are only ever used through its context, so they can be sent along with it.
+unsafe impl<'a, C: Send> Send for GenericCompiler<'a, C> {}
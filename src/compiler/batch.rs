@@ -0,0 +1,194 @@
+use super::{GenericCompiler, Resource};
+use crate::{Backend, CompilerOption, Context, Decoration, ResourceType, Result};
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// Value of a compiler option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionValue {
+    Bool(bool),
+    Uint(u32),
+}
+
+/// A resource of a compiled module, along with its binding decorations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReflectedResource {
+    pub ty: ResourceType,
+    pub resource: Resource,
+    pub set: Option<u32>,
+    pub binding: Option<u32>,
+    pub location: Option<u32>,
+}
+
+/// Result of a compiled job: the emitted source and the resources of the module.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompileOutput {
+    pub source: String,
+    /// Resources of every type, reflected after compilation, so they reflect the bindings of the source.
+    pub resources: Vec<ReflectedResource>,
+}
+
+/// Resource types reflected by [`CompileJob::compile_with_reflection`],
+/// which are all those listed by `spvc_resources_get_resource_list_for_type`.
+const REFLECTED_TYPES: [ResourceType; 12] = [
+    ResourceType::UniformBuffer,
+    ResourceType::StorageBuffer,
+    ResourceType::StageInput,
+    ResourceType::StageOutput,
+    ResourceType::SubpassInput,
+    ResourceType::StorageImage,
+    ResourceType::SampledImage,
+    ResourceType::AtomicCounter,
+    ResourceType::PushConstant,
+    ResourceType::SeparateImage,
+    ResourceType::SeparateSamplers,
+    ResourceType::AccelerationStructure,
+];
+
+/// A module to be compiled to a target with a set of options.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompileJob<'a> {
+    pub words: &'a [u32],
+    pub backend: Backend,
+    /// Options applied to the compiler, in order.
+    pub options: Vec<(CompilerOption, OptionValue)>,
+}
+
+impl<'a> CompileJob<'a> {
+    /// Creates a new job with the default options of the backend.
+    #[inline]
+    pub fn new(words: &'a [u32], backend: Backend) -> Self {
+        return Self {
+            words,
+            backend,
+            options: Vec::new(),
+        };
+    }
+
+    /// Sets the compiler's `option` property to the specified integer value.
+    #[inline]
+    pub fn set_uint(mut self, option: CompilerOption, value: u32) -> Self {
+        self.options.push((option, OptionValue::Uint(value)));
+        return self;
+    }
+
+    /// Sets the compiler's `option` property to the specified boolean value.
+    #[inline]
+    pub fn set_bool(mut self, option: CompilerOption, value: bool) -> Self {
+        self.options.push((option, OptionValue::Bool(value)));
+        return self;
+    }
+
+    /// Compiles the job on the specified context.
+    pub fn compile(&self, ctx: &mut Context) -> Result<String> {
+        return self.compiler(ctx)?.compile_mut();
+    }
+
+    /// Compiles the job on the specified context, reflecting the resources of the module afterwards.
+    pub fn compile_with_reflection(&self, ctx: &mut Context) -> Result<CompileOutput> {
+        let mut compiler = self.compiler(ctx)?;
+        let source = compiler.compile_mut()?;
+
+        let shader_resources = compiler.shader_resources()?;
+        let mut resources = Vec::new();
+        for ty in REFLECTED_TYPES {
+            for resource in shader_resources.resource_list(ty)? {
+                resources.push(ReflectedResource {
+                    ty,
+                    set: compiler.decoration(resource.id, Decoration::DescriptorSet),
+                    binding: compiler.decoration(resource.id, Decoration::Binding),
+                    location: compiler.decoration(resource.id, Decoration::Location),
+                    resource,
+                });
+            }
+        }
+
+        return Ok(CompileOutput { source, resources });
+    }
+
+    fn compiler<'c>(&self, ctx: &'c mut Context) -> Result<GenericCompiler<'c>> {
+        let mut compiler = GenericCompiler::new(ctx, self.backend, self.words)?;
+        for &(option, value) in self.options.iter() {
+            match value {
+                OptionValue::Bool(value) => compiler.set_bool_mut(option, value)?,
+                OptionValue::Uint(value) => compiler.set_uint_mut(option, value)?,
+            }
+        }
+        return Ok(compiler);
+    }
+}
+
+/// Compiles every job across as many threads as the available parallelism, returning the results
+/// in the same order as the jobs.
+pub fn compile_batch(jobs: &[CompileJob<'_>]) -> Vec<Result<String>> {
+    let threads = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+    return compile_batch_with_threads(jobs, threads);
+}
+
+/// Compiles every job across up to `threads` threads, returning the results in the same order as the jobs.
+///
+/// Each thread compiles its jobs on its own [`Context`], whose allocations are released after every job.
+pub fn compile_batch_with_threads(
+    jobs: &[CompileJob<'_>],
+    threads: NonZeroUsize,
+) -> Vec<Result<String>> {
+    return run_batch(jobs, threads, |job, ctx| job.compile(ctx));
+}
+
+/// Runs `compile` on every job across up to `threads` threads, each with its own [`Context`].
+pub(super) fn run_batch<T: Send>(
+    jobs: &[CompileJob<'_>],
+    threads: NonZeroUsize,
+    compile: fn(&CompileJob<'_>, &mut Context) -> Result<T>,
+) -> Vec<Result<T>> {
+    let next = AtomicUsize::new(0);
+    let worker = || {
+        let mut results = Vec::new();
+        let mut ctx = Context::new();
+
+        loop {
+            let idx = next.fetch_add(1, Ordering::Relaxed);
+            let Some(job) = jobs.get(idx) else { break };
+
+            let result = match ctx {
+                Ok(ref mut ctx) => {
+                    let result = compile(job, ctx);
+                    ctx.release_allocations();
+                    result
+                }
+                Err(ref e) => Err(e.clone()),
+            };
+            results.push((idx, result));
+        }
+
+        return results;
+    };
+
+    let mut results = Vec::with_capacity(jobs.len());
+    results.resize_with(jobs.len(), || None);
+
+    thread::scope(|s| {
+        let workers = (0..threads.get().min(jobs.len()))
+            .map(|_| s.spawn(worker))
+            .collect::<Vec<_>>();
+
+        for worker in workers {
+            let worker_results = match worker.join() {
+                Ok(worker_results) => worker_results,
+                Err(payload) => std::panic::resume_unwind(payload),
+            };
+
+            for (idx, result) in worker_results {
+                results[idx] = Some(result);
+            }
+        }
+    });
+
+    return results
+        .into_iter()
+        .map(|result| result.expect("every job is compiled by a worker"))
+        .collect();
+}
@@ -0,0 +1,250 @@
+use super::{
+    batch::run_batch, CompileJob, CompileOutput, OptionValue, ReflectedResource, Resource,
+};
+use crate::{sys, Context, ResourceType, Result, SPVC_VERSION};
+use std::{
+    fmt::Display,
+    fs, io,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+/// Key of a cached compilation, derived from everything that affects its result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheKey(pub u128);
+
+impl CacheKey {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    /// Computes the key of the job, hashing its words, backend and options,
+    /// alongside the versions of this crate and of the SPIRV-Cross API.
+    pub fn new(job: &CompileJob<'_>) -> Self {
+        let mut key = Self(Self::OFFSET_BASIS);
+        key.write(env!("CARGO_PKG_VERSION").as_bytes());
+        key.write(SPVC_VERSION.to_string().as_bytes());
+        key.write_u32(sys::spvc_backend::from(job.backend) as u32);
+
+        key.write_u32(job.options.len() as u32);
+        for &(option, value) in job.options.iter() {
+            key.write_u32(sys::spvc_compiler_option::from(option) as u32);
+            match value {
+                OptionValue::Bool(value) => {
+                    key.write_u32(0);
+                    key.write_u32(value as u32);
+                }
+                OptionValue::Uint(value) => {
+                    key.write_u32(1);
+                    key.write_u32(value);
+                }
+            }
+        }
+
+        key.write_u32(job.words.len() as u32);
+        for &word in job.words {
+            key.write_u32(word);
+        }
+
+        return key;
+    }
+
+    // 128-bit FNV-1a, which unlike `std`'s hashers is stable across builds.
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u128;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    #[inline]
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes())
+    }
+}
+
+impl Display for CacheKey {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:032x}", self.0)
+    }
+}
+
+/// Cache of compilation outputs, the emitted sources along with their reflected resources,
+/// stored as one file per compilation inside a directory.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Opens the cache stored at `dir`, creating the directory if it doesn't exist.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        return Ok(Self { dir });
+    }
+
+    #[inline]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the cached output of the job, if any.
+    pub fn get(&self, job: &CompileJob<'_>) -> Option<CompileOutput> {
+        let entry = fs::read_to_string(self.path(CacheKey::new(job))).ok()?;
+        return decode(&entry);
+    }
+
+    /// Stores the output of the job.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] if a resource name contains a line break.
+    pub fn insert(&self, job: &CompileJob<'_>, output: &CompileOutput) -> io::Result<()> {
+        let entry = encode(output)?;
+        let path = self.path(CacheKey::new(job));
+
+        // Write to a temporary file first, so that other processes never read a partial entry.
+        // The name is unique per write, as threads of the same process may insert the same entry.
+        static WRITES: AtomicU64 = AtomicU64::new(0);
+        let tmp = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            WRITES.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&tmp, entry)?;
+        return fs::rename(&tmp, &path).inspect_err(|_| {
+            let _ = fs::remove_file(&tmp);
+        });
+    }
+
+    /// Removes every entry of the cache.
+    pub fn clear(&self) -> io::Result<()> {
+        fs::remove_dir_all(&self.dir)?;
+        return fs::create_dir_all(&self.dir);
+    }
+
+    /// Returns the cached output of the job, compiling and storing it on a miss.
+    ///
+    /// Failing to store the output doesn't fail the compilation, it only means it will be compiled again.
+    pub fn compile(&self, job: &CompileJob<'_>, ctx: &mut Context) -> Result<CompileOutput> {
+        if let Some(output) = self.get(job) {
+            return Ok(output);
+        }
+
+        let output = job.compile_with_reflection(ctx)?;
+        let _ = self.insert(job, &output);
+        return Ok(output);
+    }
+
+    /// Like [`compile_batch`](super::compile_batch), but only compiling the jobs that miss the cache.
+    pub fn compile_batch(&self, jobs: &[CompileJob<'_>]) -> Vec<Result<CompileOutput>> {
+        let mut results = jobs
+            .iter()
+            .map(|job| self.get(job).map(Ok))
+            .collect::<Vec<_>>();
+
+        let (miss_indices, misses): (Vec<_>, Vec<_>) = jobs
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| results[*i].is_none())
+            .map(|(i, job)| (i, job.clone()))
+            .unzip();
+
+        let threads = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+        let outputs = run_batch(&misses, threads, |job, ctx| {
+            job.compile_with_reflection(ctx)
+        });
+        for (i, result) in miss_indices.into_iter().zip(outputs) {
+            if let Ok(ref output) = result {
+                let _ = self.insert(&jobs[i], output);
+            }
+            results[i] = Some(result);
+        }
+
+        return results
+            .into_iter()
+            .map(|result| result.expect("every miss is compiled"))
+            .collect();
+    }
+
+    #[inline]
+    fn path(&self, key: CacheKey) -> PathBuf {
+        self.dir.join(format!("{key}.out"))
+    }
+}
+
+// Entries start with the number of resources, followed by one resource per line and the source:
+// `<type> <id> <base type id> <type id> <set> <binding> <location> <name>`, with `-` for missing decorations.
+fn encode(output: &CompileOutput) -> io::Result<String> {
+    let mut entry = format!("{}\n", output.resources.len());
+    for reflected in output.resources.iter() {
+        let resource = &reflected.resource;
+        if resource.name.contains(['\n', '\r']) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Resource name `{}` contains a line break.",
+                    resource.name.escape_debug()
+                ),
+            ));
+        }
+
+        let decoration =
+            |value: Option<u32>| value.map_or_else(|| "-".to_owned(), |v| v.to_string());
+        entry += &format!(
+            "{} {} {} {} {} {} {} {}\n",
+            sys::spvc_resource_type::from(reflected.ty) as u32,
+            resource.id,
+            resource.base_type_id,
+            resource.type_id,
+            decoration(reflected.set),
+            decoration(reflected.binding),
+            decoration(reflected.location),
+            resource.name,
+        );
+    }
+
+    entry += &output.source;
+    return Ok(entry);
+}
+
+// Entries that can't be decoded, like those written by other versions, are treated as misses.
+fn decode(entry: &str) -> Option<CompileOutput> {
+    let (count, mut rest) = entry.split_once('\n')?;
+    let count = count.parse::<usize>().ok()?;
+
+    let mut resources = Vec::new();
+    for _ in 0..count {
+        let (line, tail) = rest.split_once('\n')?;
+        rest = tail;
+
+        let mut fields = line.splitn(8, ' ');
+        let mut number = || fields.next()?.parse::<u32>().ok();
+        let (ty, id, base_type_id, type_id) = (number()?, number()?, number()?, number()?);
+
+        let mut decoration = || match fields.next()? {
+            "-" => Some(None),
+            value => value.parse::<u32>().ok().map(Some),
+        };
+        let (set, binding, location) = (decoration()?, decoration()?, decoration()?);
+
+        resources.push(ReflectedResource {
+            ty: ResourceType::try_from(ty).ok()?,
+            resource: Resource {
+                id,
+                base_type_id,
+                type_id,
+                name: fields.next()?.to_owned(),
+            },
+            set,
+            binding,
+            location,
+        });
+    }
+
+    return Some(CompileOutput {
+        source: rest.to_owned(),
+        resources,
+    });
+}
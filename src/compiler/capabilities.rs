@@ -0,0 +1,58 @@
+use super::GenericCompiler;
+use crate::{sys, Capability, Context, Result};
+use std::{borrow::Borrow, ffi::CStr, mem::MaybeUninit};
+
+impl<'a, C: Borrow<Context>> GenericCompiler<'a, C> {
+    /// Returns the capabilities declared by the module.
+    ///
+    /// Capabilities unknown to the bundled SPIR-V headers are skipped.
+    pub fn declared_capabilities(&self) -> Result<Vec<Capability>> {
+        let mut capabilities = MaybeUninit::uninit();
+        let mut len = MaybeUninit::uninit();
+
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_get_declared_capabilities(
+                    self.compiler,
+                    capabilities.as_mut_ptr(),
+                    len.as_mut_ptr(),
+                ))?;
+
+            let (capabilities, len) = (capabilities.assume_init(), len.assume_init());
+            if len == 0 {
+                return Ok(Vec::new());
+            }
+
+            // Read as raw values, since newer capabilities aren't valid `sys::SpvCapability`s
+            return Ok(core::slice::from_raw_parts(capabilities.cast::<u32>(), len)
+                .iter()
+                .filter_map(|&capability| Capability::try_from(capability).ok())
+                .collect());
+        }
+    }
+
+    /// Returns the extensions declared by the module.
+    pub fn declared_extensions(&self) -> Result<Vec<String>> {
+        let mut extensions = MaybeUninit::uninit();
+        let mut len = MaybeUninit::uninit();
+
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_get_declared_extensions(
+                    self.compiler,
+                    extensions.as_mut_ptr(),
+                    len.as_mut_ptr(),
+                ))?;
+
+            let (extensions, len) = (extensions.assume_init(), len.assume_init());
+            if len == 0 {
+                return Ok(Vec::new());
+            }
+
+            return Ok(core::slice::from_raw_parts(extensions, len)
+                .iter()
+                .map(|&extension| CStr::from_ptr(extension).to_string_lossy().into_owned())
+                .collect());
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use super::GenericCompiler;
+use crate::{sys, Context, Decoration, Result};
+use std::{
+    borrow::Borrow,
+    ffi::{CStr, CString},
+};
+
+impl<'a, C: Borrow<Context>> GenericCompiler<'a, C> {
+    #[inline]
+    pub fn has_decoration(&self, id: u32, decoration: Decoration) -> bool {
+        unsafe { sys::spvc_compiler_has_decoration(self.compiler, id, decoration.into()) != 0 }
+    }
+
+    /// Returns the value of the decoration, or `None` if the id isn't decorated with it.
+    ///
+    /// Decorations without a value (like `NonWritable`) return `Some(1)` when present.
+    pub fn decoration(&self, id: u32, decoration: Decoration) -> Option<u32> {
+        if !self.has_decoration(id, decoration) {
+            return None;
+        }
+        unsafe {
+            return Some(sys::spvc_compiler_get_decoration(
+                self.compiler,
+                id,
+                decoration.into(),
+            ));
+        }
+    }
+
+    /// Returns the string value of the decoration (like `UserSemantic`), or `None` if the id isn't decorated with it.
+    pub fn decoration_string(&self, id: u32, decoration: Decoration) -> Option<String> {
+        if !self.has_decoration(id, decoration) {
+            return None;
+        }
+        unsafe {
+            let value =
+                sys::spvc_compiler_get_decoration_string(self.compiler, id, decoration.into());
+            return Some(CStr::from_ptr(value).to_string_lossy().into_owned());
+        }
+    }
+
+    #[inline]
+    pub fn set_decoration(&mut self, id: u32, decoration: Decoration, value: u32) {
+        unsafe { sys::spvc_compiler_set_decoration(self.compiler, id, decoration.into(), value) }
+    }
+
+    pub fn set_decoration_string(
+        &mut self,
+        id: u32,
+        decoration: Decoration,
+        value: &str,
+    ) -> Result<()> {
+        let value = CString::new(value)?;
+        unsafe {
+            sys::spvc_compiler_set_decoration_string(
+                self.compiler,
+                id,
+                decoration.into(),
+                value.as_ptr(),
+            );
+        }
+        return Ok(());
+    }
+
+    #[inline]
+    pub fn unset_decoration(&mut self, id: u32, decoration: Decoration) {
+        unsafe { sys::spvc_compiler_unset_decoration(self.compiler, id, decoration.into()) }
+    }
+
+    #[inline]
+    pub fn has_member_decoration(&self, type_id: u32, member: u32, decoration: Decoration) -> bool {
+        unsafe {
+            sys::spvc_compiler_has_member_decoration(
+                self.compiler,
+                type_id,
+                member,
+                decoration.into(),
+            ) != 0
+        }
+    }
+
+    /// Returns the value of the struct member's decoration, or `None` if the member isn't decorated with it.
+    pub fn member_decoration(
+        &self,
+        type_id: u32,
+        member: u32,
+        decoration: Decoration,
+    ) -> Option<u32> {
+        if !self.has_member_decoration(type_id, member, decoration) {
+            return None;
+        }
+        unsafe {
+            return Some(sys::spvc_compiler_get_member_decoration(
+                self.compiler,
+                type_id,
+                member,
+                decoration.into(),
+            ));
+        }
+    }
+
+    /// Returns the string value of the struct member's decoration, or `None` if the member isn't decorated with it.
+    pub fn member_decoration_string(
+        &self,
+        type_id: u32,
+        member: u32,
+        decoration: Decoration,
+    ) -> Option<String> {
+        if !self.has_member_decoration(type_id, member, decoration) {
+            return None;
+        }
+        unsafe {
+            let value = sys::spvc_compiler_get_member_decoration_string(
+                self.compiler,
+                type_id,
+                member,
+                decoration.into(),
+            );
+            return Some(CStr::from_ptr(value).to_string_lossy().into_owned());
+        }
+    }
+
+    #[inline]
+    pub fn set_member_decoration(
+        &mut self,
+        type_id: u32,
+        member: u32,
+        decoration: Decoration,
+        value: u32,
+    ) {
+        unsafe {
+            sys::spvc_compiler_set_member_decoration(
+                self.compiler,
+                type_id,
+                member,
+                decoration.into(),
+                value,
+            )
+        }
+    }
+
+    pub fn set_member_decoration_string(
+        &mut self,
+        type_id: u32,
+        member: u32,
+        decoration: Decoration,
+        value: &str,
+    ) -> Result<()> {
+        let value = CString::new(value)?;
+        unsafe {
+            sys::spvc_compiler_set_member_decoration_string(
+                self.compiler,
+                type_id,
+                member,
+                decoration.into(),
+                value.as_ptr(),
+            );
+        }
+        return Ok(());
+    }
+
+    #[inline]
+    pub fn unset_member_decoration(&mut self, type_id: u32, member: u32, decoration: Decoration) {
+        unsafe {
+            sys::spvc_compiler_unset_member_decoration(
+                self.compiler,
+                type_id,
+                member,
+                decoration.into(),
+            )
+        }
+    }
+}
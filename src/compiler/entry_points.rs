@@ -0,0 +1,221 @@
+use super::GenericCompiler;
+use crate::{sys, Context, Error, ExecutionMode, ExecutionModel, Result};
+use std::{
+    borrow::Borrow,
+    ffi::{CStr, CString},
+    mem::MaybeUninit,
+};
+
+/// An entry point of the module.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EntryPoint {
+    pub name: String,
+    pub execution_model: ExecutionModel,
+}
+
+/// Local workgroup size of a compute-like entry point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WorkgroupSize {
+    pub x: WorkgroupDimension,
+    pub y: WorkgroupDimension,
+    pub z: WorkgroupDimension,
+}
+
+/// A dimension of the local workgroup size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WorkgroupDimension {
+    /// Declared size of the dimension (the default value, if it's driven by a specialization constant).
+    pub size: u32,
+    /// `constant_id` of the specialization constant driving the dimension, if any.
+    pub constant_id: Option<u32>,
+}
+
+impl WorkgroupSize {
+    /// Returns the declared sizes of the dimensions, as `[x, y, z]`.
+    #[inline]
+    pub fn sizes(&self) -> [u32; 3] {
+        return [self.x.size, self.y.size, self.z.size];
+    }
+}
+
+impl<'a, C: Borrow<Context>> GenericCompiler<'a, C> {
+    /// Returns the entry points declared by the module.
+    ///
+    /// Entry points whose execution model is unknown to the bundled SPIR-V headers are skipped.
+    pub fn entry_points(&self) -> Result<Vec<EntryPoint>> {
+        let mut entry_points = MaybeUninit::uninit();
+        let mut len = MaybeUninit::uninit();
+
+        unsafe {
+            self.ctx.get_error(sys::spvc_compiler_get_entry_points(
+                self.compiler,
+                entry_points.as_mut_ptr(),
+                len.as_mut_ptr(),
+            ))?;
+
+            let (entry_points, len) = (entry_points.assume_init(), len.assume_init());
+            if len == 0 {
+                return Ok(Vec::new());
+            }
+
+            // Fields are read through raw pointers, since newer execution models aren't valid `sys::SpvExecutionModel`s
+            return Ok((0..len)
+                .map(|i| entry_points.add(i))
+                .filter_map(|entry_point| {
+                    let execution_model = core::ptr::addr_of!((*entry_point).execution_model)
+                        .cast::<u32>()
+                        .read();
+                    Some(EntryPoint {
+                        name: CStr::from_ptr((*entry_point).name)
+                            .to_string_lossy()
+                            .into_owned(),
+                        execution_model: ExecutionModel::try_from(execution_model).ok()?,
+                    })
+                })
+                .collect());
+        }
+    }
+
+    /// Selects the entry point that will be compiled and reflected upon.
+    pub fn set_entry_point(&mut self, name: &str, execution_model: ExecutionModel) -> Result<()> {
+        // SPIRV-Cross doesn't catch the exception thrown for unknown entry points,
+        // so they must be rejected before crossing the FFI boundary.
+        if !self.entry_points()?.iter().any(|entry_point| {
+            entry_point.name == name && entry_point.execution_model == execution_model
+        }) {
+            return Err(Error {
+                code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                err_msg: Some(format!("Entry point `{name}` does not exist.")),
+            });
+        }
+
+        let name = CString::new(name)?;
+        unsafe {
+            return self.ctx.get_error(sys::spvc_compiler_set_entry_point(
+                self.compiler,
+                name.as_ptr(),
+                execution_model.into(),
+            ));
+        }
+    }
+
+    /// Renames an entry point, changing the name of the function in the emitted source.
+    pub fn rename_entry_point(
+        &mut self,
+        old_name: &str,
+        new_name: &str,
+        execution_model: ExecutionModel,
+    ) -> Result<()> {
+        let old_name = CString::new(old_name)?;
+        let new_name = CString::new(new_name)?;
+        unsafe {
+            return self.ctx.get_error(sys::spvc_compiler_rename_entry_point(
+                self.compiler,
+                old_name.as_ptr(),
+                new_name.as_ptr(),
+                execution_model.into(),
+            ));
+        }
+    }
+
+    /// Returns the name the entry point has in the emitted source,
+    /// which might differ from the declared one if it isn't a valid identifier in the target language.
+    ///
+    /// The name is only cleansed once the program is compiled.
+    pub fn cleansed_entry_point_name(
+        &self,
+        name: &str,
+        execution_model: ExecutionModel,
+    ) -> Result<String> {
+        let name = CString::new(name)?;
+        unsafe {
+            let cleansed = sys::spvc_compiler_get_cleansed_entry_point_name(
+                self.compiler,
+                name.as_ptr(),
+                execution_model.into(),
+            );
+
+            if cleansed.is_null() {
+                self.ctx
+                    .get_error(sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT)?;
+            }
+
+            return Ok(CStr::from_ptr(cleansed).to_string_lossy().into_owned());
+        }
+    }
+
+    /// Returns the execution model of the selected entry point.
+    #[inline]
+    pub fn execution_model(&self) -> Result<ExecutionModel> {
+        unsafe { ExecutionModel::try_from(sys::spvc_compiler_get_execution_model(self.compiler)) }
+    }
+
+    /// Returns the execution modes declared for the selected entry point.
+    ///
+    /// Modes unknown to the bundled SPIR-V headers are skipped.
+    pub fn execution_modes(&self) -> Result<Vec<ExecutionMode>> {
+        let mut modes = MaybeUninit::uninit();
+        let mut len = MaybeUninit::uninit();
+
+        unsafe {
+            self.ctx.get_error(sys::spvc_compiler_get_execution_modes(
+                self.compiler,
+                modes.as_mut_ptr(),
+                len.as_mut_ptr(),
+            ))?;
+
+            let (modes, len) = (modes.assume_init(), len.assume_init());
+            if len == 0 {
+                return Ok(Vec::new());
+            }
+
+            // Read as raw values, since newer modes aren't valid `sys::SpvExecutionMode`s
+            return Ok(core::slice::from_raw_parts(modes.cast::<u32>(), len)
+                .iter()
+                .filter_map(|&mode| ExecutionMode::try_from(mode).ok())
+                .collect());
+        }
+    }
+
+    /// Returns the argument at `index` of the execution mode (like the vertex count of `OutputVertices`),
+    /// or 0 if the mode isn't declared or has no such argument.
+    #[inline]
+    pub fn execution_mode_argument(&self, mode: ExecutionMode, index: u32) -> u32 {
+        unsafe {
+            sys::spvc_compiler_get_execution_mode_argument_by_index(
+                self.compiler,
+                mode.into(),
+                index,
+            )
+        }
+    }
+
+    /// Returns the local workgroup size of the selected entry point, along with the
+    /// specialization constants that drive each dimension.
+    pub fn workgroup_size(&self) -> WorkgroupSize {
+        let mut x = MaybeUninit::uninit();
+        let mut y = MaybeUninit::uninit();
+        let mut z = MaybeUninit::uninit();
+
+        unsafe {
+            sys::spvc_compiler_get_work_group_size_specialization_constants(
+                self.compiler,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                z.as_mut_ptr(),
+            );
+
+            let dimension =
+                |index: u32, constant: sys::spvc_specialization_constant| WorkgroupDimension {
+                    size: self.execution_mode_argument(ExecutionMode::LocalSize, index),
+                    constant_id: (constant.id != 0).then_some(constant.constant_id),
+                };
+
+            return WorkgroupSize {
+                x: dimension(0, x.assume_init()),
+                y: dimension(1, y.assume_init()),
+                z: dimension(2, z.assume_init()),
+            };
+        }
+    }
+}
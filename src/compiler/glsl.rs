@@ -1,23 +1,101 @@
-use super::{Compiler, GenericCompiler};
-use crate::sys::{self, spvc_compiler_option};
+use super::{BorrowedContext, Compiler, GenericCompiler, ParsedIr, Resource};
+use crate::sys;
+use crate::Backend;
+use crate::BaseType;
+use crate::CompilerOption;
 use crate::Context;
+use crate::Decoration;
+use crate::Error;
 use crate::Result;
 use semver::Version;
+use std::{
+    borrow::Borrow,
+    ffi::{CStr, CString},
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+
+mod bindings;
+
+#[doc(inline)]
+pub use bindings::{BindingClass, BindingRemap, BindingTableEntry, GlslOutput};
+
+/// A combined image sampler synthesized from a separate image and sampler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CombinedImageSampler {
+    /// Id of the synthesized variable, to be named and decorated like any other.
+    pub combined_id: u32,
+    pub image_id: u32,
+    pub sampler_id: u32,
+}
+
+/// A uniform buffer block emitted as a plain `vec4` array.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FlattenedBufferBlock {
+    /// Id of the block's variable.
+    pub id: u32,
+    /// Name of the emitted array, which is the name of the block's type.
+    pub name: String,
+    /// Length of the emitted array, in `vec4`s.
+    pub vec4_count: usize,
+}
 
 /// OpenGL Shading Language compiler.
-pub struct GlslCompiler<'a> {
-    inner: GenericCompiler<'a>,
+pub struct GlslCompiler<'a, C = &'a mut Context> {
+    inner: GenericCompiler<'a, C>,
 }
 
 impl<'a> GlslCompiler<'a> {
     pub fn new(ctx: &'a mut Context, words: &[u32]) -> Result<Self> {
         return Ok(Self {
-            inner: GenericCompiler::new(ctx, sys::spvc_backend::SPVC_BACKEND_GLSL, words)?,
+            inner: GenericCompiler::new(ctx, Backend::Glsl, words)?,
+        });
+    }
+}
+
+impl<'a> GlslCompiler<'a, &'a Context> {
+    /// Creates a new compiler working on a copy of the already parsed module, see [`ParsedIr::compiler`].
+    pub fn from_parsed_ir<C: Borrow<Context>>(parsed_ir: &'a ParsedIr<'_, C>) -> Result<Self> {
+        return Ok(Self {
+            inner: parsed_ir.compiler(Backend::Glsl)?,
         });
     }
+}
 
+impl<'a, C: BorrowedContext<'a>> GlslCompiler<'a, C> {
+    /// Compiles the program, returning a reference to the context's resulting C string,
+    /// see [`GenericCompiler::raw_compile`].
     #[inline]
-    pub fn into_generic(self) -> GenericCompiler<'a> {
+    pub fn raw_compile(self) -> Result<&'a CStr> {
+        self.inner.raw_compile()
+    }
+}
+
+impl GlslCompiler<'static, Context> {
+    /// Creates a new compiler that owns its context, see [`GenericCompiler::new_owned`].
+    ///
+    /// The returned compiler has no `raw_compile`, as its result would be freed along with the context;
+    /// use [`GenericCompiler::raw_compile_mut`] or [`Compiler::compile`] instead.
+    pub fn new_owned(ctx: Context, words: &[u32]) -> Result<Self> {
+        return Ok(Self {
+            inner: GenericCompiler::new_owned(ctx, Backend::Glsl, words)?,
+        });
+    }
+}
+
+impl GlslCompiler<'static, Rc<Context>> {
+    /// Creates a new compiler sharing the context of the already parsed module, see [`ParsedIr::shared_compiler`].
+    pub fn from_shared_parsed_ir(parsed_ir: &ParsedIr<'static, Rc<Context>>) -> Result<Self> {
+        return Ok(Self {
+            inner: parsed_ir.shared_compiler(Backend::Glsl)?,
+        });
+    }
+}
+
+impl<'a, C: Borrow<Context>> GlslCompiler<'a, C> {
+    #[inline]
+    pub fn into_generic(self) -> GenericCompiler<'a, C> {
         self.inner
     }
 
@@ -27,10 +105,7 @@ impl<'a> GlslCompiler<'a> {
 
     pub fn version(mut self, major: u32, minor: u32) -> Result<Self> {
         let version = (100 * major) + (10 * minor);
-        self.inner = self.inner.set_uint(
-            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_VERSION,
-            version,
-        )?;
+        self.inner = self.inner.set_uint(CompilerOption::GlslVersion, version)?;
         return Ok(self);
     }
 
@@ -40,34 +115,27 @@ impl<'a> GlslCompiler<'a> {
             Some(EsOptions {
                 default_float_precision_highp,
                 default_int_precision_highp,
-            }) => {
-                let mut inner = self
-                    .inner
-                    .set_bool(spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_ES, true)?;
-
-                if let Some(default_float_precision_highp) = default_float_precision_highp {
-                    inner = inner.set_uint(spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_ES_DEFAULT_FLOAT_PRECISION_HIGHP, default_float_precision_highp)?
-                }
-
-                if let Some(default_int_precision_highp) = default_int_precision_highp {
-                    inner = inner.set_uint(spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_ES_DEFAULT_INT_PRECISION_HIGHP, default_int_precision_highp)?
-                }
-
-                inner
-            }
-            None => self
+            }) => self
                 .inner
-                .set_bool(spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_ES, false)?,
+                .set_bool(CompilerOption::GlslEs, true)?
+                .set_bool(
+                    CompilerOption::GlslEsDefaultFloatPrecisionHighp,
+                    default_float_precision_highp,
+                )?
+                .set_bool(
+                    CompilerOption::GlslEsDefaultIntPrecisionHighp,
+                    default_int_precision_highp,
+                )?,
+            None => self.inner.set_bool(CompilerOption::GlslEs, false)?,
         };
 
         return Ok(self);
     }
 
     pub fn vulkan_semantics(mut self, vulkan_semantics: bool) -> Result<Self> {
-        self.inner = self.inner.set_bool(
-            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_VULKAN_SEMANTICS,
-            vulkan_semantics,
-        )?;
+        self.inner = self
+            .inner
+            .set_bool(CompilerOption::GlslVulkanSemantics, vulkan_semantics)?;
         return Ok(self);
     }
 
@@ -76,15 +144,22 @@ impl<'a> GlslCompiler<'a> {
         support_nonzero_base_instance: bool,
     ) -> Result<Self> {
         self.inner = self.inner.set_bool(
-            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_SUPPORT_NONZERO_BASE_INSTANCE,
+            CompilerOption::GlslSupportNonzeroBaseInstance,
             support_nonzero_base_instance,
         )?;
         return Ok(self);
     }
 
+    pub fn separate_shader_objects(self, separate_shader_objects: bool) -> Result<Self> {
+        return self.set_bool(
+            CompilerOption::GlslSeparateShaderObjects,
+            separate_shader_objects,
+        );
+    }
+
     pub fn enable_420_pack_extension(self, enable_420_pack_extension: bool) -> Result<Self> {
         return self.set_bool(
-            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_ENABLE_420PACK_EXTENSION,
+            CompilerOption::GlslEnable420packExtension,
             enable_420_pack_extension,
         );
     }
@@ -94,7 +169,7 @@ impl<'a> GlslCompiler<'a> {
         emit_push_constant_as_uniform_buffer: bool,
     ) -> Result<Self> {
         return self.set_bool(
-            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_EMIT_PUSH_CONSTANT_AS_UNIFORM_BUFFER,
+            CompilerOption::GlslEmitPushConstantAsUniformBuffer,
             emit_push_constant_as_uniform_buffer,
         );
     }
@@ -104,21 +179,21 @@ impl<'a> GlslCompiler<'a> {
         emit_uniform_buffer_as_plain_uniforms: bool,
     ) -> Result<Self> {
         return self.set_bool(
-            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_EMIT_UNIFORM_BUFFER_AS_PLAIN_UNIFORMS,
+            CompilerOption::GlslEmitUniformBufferAsPlainUniforms,
             emit_uniform_buffer_as_plain_uniforms,
         );
     }
 
     pub fn force_flattened_io_blocks(self, force_flattened_io_blocks: bool) -> Result<Self> {
         return self.set_bool(
-            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_FORCE_FLATTENED_IO_BLOCKS,
+            CompilerOption::GlslForceFlattenedIoBlocks,
             force_flattened_io_blocks,
         );
     }
 
     pub fn ovr_multiview_view_count(self, ovr_multiview_view_count: u32) -> Result<Self> {
         return self.set_uint(
-            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_OVR_MULTIVIEW_VIEW_COUNT,
+            CompilerOption::GlslOvrMultiviewViewCount,
             ovr_multiview_view_count,
         );
     }
@@ -128,42 +203,228 @@ impl<'a> GlslCompiler<'a> {
         enable_row_major_load_workaround: bool,
     ) -> Result<Self> {
         return self.set_bool(
-            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_ENABLE_ROW_MAJOR_LOAD_WORKAROUND,
+            CompilerOption::GlslEnableRowMajorLoadWorkaround,
             enable_row_major_load_workaround,
         );
     }
+
+    /// Adds a line to the emitted source, right after the `#version` directive and the required extensions.
+    ///
+    /// Useful to inject preprocessor defines, precision statements or pragmas.
+    pub fn add_header_line(&mut self, line: &str) -> Result<()> {
+        let line = CString::new(line)?;
+        unsafe {
+            return self.inner.ctx.get_error(sys::spvc_compiler_add_header_line(
+                self.inner.compiler,
+                line.as_ptr(),
+            ));
+        }
+    }
+
+    /// Requires the specified extension (e.g. `GL_EXT_shader_io_blocks`), emitting its `#extension`
+    /// directive alongside the ones required by the shader itself.
+    pub fn require_extension(&mut self, extension: &str) -> Result<()> {
+        let extension = CString::new(extension)?;
+        unsafe {
+            return self
+                .inner
+                .ctx
+                .get_error(sys::spvc_compiler_require_extension(
+                    self.inner.compiler,
+                    extension.as_ptr(),
+                ));
+        }
+    }
+
+    /// Emits the uniform buffer block as a single plain `vec4` array, like `uniform vec4 Block[N];`,
+    /// so it can be uploaded at once with `glUniform4fv`.
+    ///
+    /// Fails if the resource isn't a single, non-empty uniform buffer block.
+    /// All the block's members must also share the same basic type, or compilation will fail.
+    pub fn flatten_buffer_block(&mut self, block: &Resource) -> Result<FlattenedBufferBlock> {
+        let name = match self.name_of(block.base_type_id) {
+            name if name.is_empty() => format!("_{}", block.base_type_id),
+            name => name,
+        };
+
+        // SPIRV-Cross doesn't catch the exceptions thrown for blocks it can't flatten,
+        // so they must be rejected before crossing the FFI boundary.
+        let reason = match self
+            .shader_resources()?
+            .uniform_buffers()?
+            .into_iter()
+            .find(|resource| resource.id == block.id)
+        {
+            None => Some("is not a uniform buffer"),
+            Some(block) => {
+                let ty = self.type_handle(block.type_id)?;
+                if !ty.array_dimensions().is_empty() {
+                    Some("is an array of uniform buffers")
+                } else if ty.base_type()? != BaseType::Struct {
+                    Some("is not a struct")
+                } else if !self.has_decoration(block.base_type_id, Decoration::Block) {
+                    Some("is not a block")
+                } else if ty.members()?.is_empty() {
+                    Some("is an empty struct")
+                } else {
+                    None
+                }
+            }
+        };
+
+        if let Some(reason) = reason {
+            return Err(Error {
+                code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                err_msg: Some(format!("Buffer block `{name}` {reason}.")),
+            });
+        }
+
+        let size = self
+            .type_handle(block.base_type_id)?
+            .declared_struct_size()?;
+
+        unsafe {
+            self.inner
+                .ctx
+                .get_error(sys::spvc_compiler_flatten_buffer_block(
+                    self.inner.compiler,
+                    block.id,
+                ))?;
+        }
+
+        return Ok(FlattenedBufferBlock {
+            id: block.id,
+            name,
+            vec4_count: size.div_ceil(16),
+        });
+    }
+
+    /// Creates a dummy sampler for the separate images that are only used with `texelFetch`,
+    /// so they can be combined by [`build_combined_image_samplers`](Self::build_combined_image_samplers),
+    /// which must be called afterwards.
+    ///
+    /// Returns the id of the dummy sampler, or `None` if no image needed one.
+    pub fn build_dummy_sampler_for_combined_images(&mut self) -> Result<Option<u32>> {
+        let mut id = MaybeUninit::uninit();
+        unsafe {
+            self.inner.ctx.get_error(
+                sys::spvc_compiler_build_dummy_sampler_for_combined_images(
+                    self.inner.compiler,
+                    id.as_mut_ptr(),
+                ),
+            )?;
+
+            return Ok(match id.assume_init() {
+                0 => None,
+                id => Some(id),
+            });
+        }
+    }
+
+    /// Synthesizes a combined image sampler for every separate image and sampler pair used together,
+    /// returning the resulting combined image samplers.
+    ///
+    /// This is required to target GLSL without Vulkan semantics from shaders using separate images and samplers,
+    /// and must be called after selecting the entry point.
+    pub fn build_combined_image_samplers(&mut self) -> Result<Vec<CombinedImageSampler>> {
+        unsafe {
+            self.inner
+                .ctx
+                .get_error(sys::spvc_compiler_build_combined_image_samplers(
+                    self.inner.compiler,
+                ))?;
+        }
+        return self.combined_image_samplers();
+    }
+
+    /// Returns the combined image samplers synthesized by [`build_combined_image_samplers`](Self::build_combined_image_samplers).
+    pub fn combined_image_samplers(&self) -> Result<Vec<CombinedImageSampler>> {
+        let mut samplers = MaybeUninit::uninit();
+        let mut len = MaybeUninit::uninit();
+
+        unsafe {
+            self.inner
+                .ctx
+                .get_error(sys::spvc_compiler_get_combined_image_samplers(
+                    self.inner.compiler,
+                    samplers.as_mut_ptr(),
+                    len.as_mut_ptr(),
+                ))?;
+
+            let (samplers, len) = (samplers.assume_init(), len.assume_init());
+            if len == 0 {
+                return Ok(Vec::new());
+            }
+
+            return Ok(core::slice::from_raw_parts(samplers, len)
+                .iter()
+                .map(|sampler| CombinedImageSampler {
+                    combined_id: sampler.combined_id,
+                    image_id: sampler.image_id,
+                    sampler_id: sampler.sampler_id,
+                })
+                .collect());
+        }
+    }
 }
 
-impl<'a> From<GlslCompiler<'a>> for GenericCompiler<'a> {
+impl<'a, C> Deref for GlslCompiler<'a, C> {
+    type Target = GenericCompiler<'a, C>;
+
     #[inline]
-    fn from(value: GlslCompiler<'a>) -> Self {
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, C> DerefMut for GlslCompiler<'a, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<'a, C> From<GlslCompiler<'a, C>> for GenericCompiler<'a, C> {
+    #[inline]
+    fn from(value: GlslCompiler<'a, C>) -> Self {
         value.inner
     }
 }
 
-impl<'a> Compiler<'a> for GlslCompiler<'a> {
-    fn raw_compile(self) -> Result<&'a std::ffi::CStr> {
-        self.inner.raw_compile()
+impl<'a, C: Borrow<Context>> Compiler<'a> for GlslCompiler<'a, C> {
+    #[inline]
+    fn compile(self) -> Result<String> {
+        self.inner.compile()
     }
 
-    fn set_uint(
-        mut self,
-        option: sys::spvc_compiler_option,
-        value: std::ffi::c_uint,
-    ) -> Result<Self> {
+    fn set_uint(mut self, option: CompilerOption, value: std::ffi::c_uint) -> Result<Self> {
         self.inner = self.inner.set_uint(option, value)?;
         return Ok(self);
     }
 
-    fn set_bool(mut self, option: sys::spvc_compiler_option, value: bool) -> Result<Self> {
+    fn set_bool(mut self, option: CompilerOption, value: bool) -> Result<Self> {
         self.inner = self.inner.set_bool(option, value)?;
         return Ok(self);
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Options of GLSL ES targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct EsOptions {
-    pub default_float_precision_highp: Option<u32>,
-    pub default_int_precision_highp: Option<u32>,
+    /// Whether floats default to `highp` instead of `mediump`.
+    pub default_float_precision_highp: bool,
+    /// Whether integers default to `highp` instead of `mediump`.
+    pub default_int_precision_highp: bool,
+}
+
+impl Default for EsOptions {
+    /// Returns the same defaults as SPIRV-Cross: `mediump` floats and `highp` integers.
+    #[inline]
+    fn default() -> Self {
+        return Self {
+            default_float_precision_highp: false,
+            default_int_precision_highp: true,
+        };
+    }
 }
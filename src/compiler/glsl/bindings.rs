@@ -0,0 +1,258 @@
+use super::GlslCompiler;
+use crate::{compiler::types::ArrayDimension, sys, Context, Decoration, Error, Result};
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, HashMap, HashSet},
+};
+
+/// Class of resources sharing the same range of GL binding points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BindingClass {
+    /// Uniform buffer blocks, bound with `glBindBufferBase(GL_UNIFORM_BUFFER, ..)`.
+    UniformBuffer,
+    /// Shader storage buffer blocks, bound with `glBindBufferBase(GL_SHADER_STORAGE_BUFFER, ..)`.
+    StorageBuffer,
+    /// Sampled textures and texel buffers, bound to texture units.
+    Texture,
+    /// Storage images, bound with `glBindImageTexture`.
+    Image,
+}
+
+/// Binding point of a resource, as named in the emitted source.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BindingTableEntry {
+    /// Id of the resource's variable.
+    pub id: u32,
+    /// Name of the uniform block, or of the uniform for other classes.
+    pub name: String,
+    pub class: BindingClass,
+    pub binding: u32,
+}
+
+/// Source emitted by a GLSL compiler, alongside the binding points of its resources.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GlslOutput {
+    pub source: String,
+    pub bindings: Vec<BindingTableEntry>,
+}
+
+/// A resource whose Vulkan descriptor set and binding have been replaced by a GL binding point.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BindingRemap {
+    /// Id of the resource's variable.
+    pub id: u32,
+    pub name: String,
+    pub class: BindingClass,
+    /// Original descriptor set of the resource.
+    pub set: u32,
+    /// Original binding of the resource within its descriptor set.
+    pub binding: u32,
+    /// GL binding point assigned to the resource, unique within its class.
+    pub gl_binding: u32,
+}
+
+impl<'a, C: Borrow<Context>> GlslCompiler<'a, C> {
+    /// Replaces the Vulkan descriptor sets and bindings of the shader's resources with GL binding points,
+    /// returning the resulting map sorted by class and binding point.
+    ///
+    /// Binding points are assigned consecutively per [`BindingClass`], in `(set, binding)` order,
+    /// so resources of different sets never collide, and resources sharing a `(set, binding)` pair share a binding point.
+    /// Arrays of resources take one binding point per element, starting at the returned one.
+    ///
+    /// If [`build_combined_image_samplers`](Self::build_combined_image_samplers) was called before, each combined
+    /// image sampler gets its own texture unit, ordered by the `(set, binding)` of its image then of its sampler,
+    /// and the combined separate images are skipped.
+    ///
+    /// Fails if a resource is an array whose size is unknown until runtime or specialization.
+    pub fn remap_vulkan_bindings(&mut self) -> Result<Vec<BindingRemap>> {
+        let mut remaps = Vec::new();
+        for resource in self.binding_resources()? {
+            let remap = BindingRemap {
+                id: resource.id,
+                name: resource.name,
+                class: resource.class,
+                set: self
+                    .decoration(resource.source, Decoration::DescriptorSet)
+                    .unwrap_or(0),
+                binding: self
+                    .decoration(resource.source, Decoration::Binding)
+                    .unwrap_or(0),
+                gl_binding: 0,
+            };
+
+            // Combined image samplers are told apart by their sampler, as an image may be sampled in several ways
+            let sampler = resource.sampler.map(|sampler| {
+                (
+                    self.decoration(sampler, Decoration::DescriptorSet)
+                        .unwrap_or(0),
+                    self.decoration(sampler, Decoration::Binding).unwrap_or(0),
+                )
+            });
+
+            let dimensions = match resource.type_id {
+                Some(type_id) => self.type_handle(type_id)?.array_dimensions(),
+                None => Vec::new(),
+            };
+
+            let mut count = 1u32;
+            for dimension in dimensions {
+                match dimension {
+                    ArrayDimension::Literal(size) if size > 0 => count *= size,
+                    _ => {
+                        return Err(Error {
+                            code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                            err_msg: Some(format!(
+                                "Resource {} is an array of unknown size, which can't be given binding points.",
+                                remap.id
+                            )),
+                        })
+                    }
+                }
+            }
+
+            remaps.push((remap, sampler, count));
+        }
+
+        // Resources sharing a slot share its binding points, so the slot is as large as its largest array
+        let mut slots = BTreeMap::<_, (u32, u32)>::new();
+        for (remap, sampler, count) in remaps.iter() {
+            let slot = slots
+                .entry((remap.class, remap.set, remap.binding, *sampler))
+                .or_default();
+            slot.1 = slot.1.max(*count);
+        }
+
+        let mut next = BTreeMap::<BindingClass, u32>::new();
+        for (&(class, _, _, _), (slot, count)) in slots.iter_mut() {
+            let next = next.entry(class).or_default();
+            *slot = *next;
+            *next += *count;
+        }
+
+        for (remap, sampler, _) in remaps.iter_mut() {
+            remap.gl_binding = slots[&(remap.class, remap.set, remap.binding, *sampler)].0;
+            self.set_decoration(remap.id, Decoration::Binding, remap.gl_binding);
+            self.unset_decoration(remap.id, Decoration::DescriptorSet);
+        }
+
+        let mut remaps = remaps
+            .into_iter()
+            .map(|(remap, _, _)| remap)
+            .collect::<Vec<_>>();
+        remaps.sort_by_key(|remap| (remap.class, remap.gl_binding, remap.id));
+        return Ok(remaps);
+    }
+
+    /// Compiles the program, returning alongside the source the binding points of its resources,
+    /// keyed by their names in the source.
+    ///
+    /// GL versions without `layout(binding)` support (GL 3.3 or GLES 3.0, unless the 420pack extension is enabled)
+    /// drop the binding qualifiers, so the table lets the runtime assign them after linking,
+    /// with `glUniformBlockBinding` for uniform blocks and `glUniform1i` for samplers.
+    pub fn compile_with_binding_table(&mut self) -> Result<GlslOutput> {
+        let source = self.compile_mut()?;
+
+        let mut bindings = Vec::new();
+        for resource in self.binding_resources()? {
+            let BindingResource {
+                class, id, name, ..
+            } = resource;
+            let Some(binding) = self
+                .decoration(id, Decoration::Binding)
+                .or_else(|| self.decoration(resource.source, Decoration::Binding))
+            else {
+                continue;
+            };
+
+            let name = match class {
+                BindingClass::UniformBuffer | BindingClass::StorageBuffer => {
+                    self.remapped_declared_block_name(id)?
+                }
+                _ if name.is_empty() => format!("_{id}"),
+                _ => name,
+            };
+
+            bindings.push(BindingTableEntry {
+                id,
+                name,
+                class,
+                binding,
+            });
+        }
+
+        bindings.sort_by_key(|entry| (entry.class, entry.binding, entry.id));
+        return Ok(GlslOutput { source, bindings });
+    }
+
+    /// Returns the resources bound to GL binding points.
+    fn binding_resources(&self) -> Result<Vec<BindingResource>> {
+        let combined = self.combined_image_samplers()?;
+        let combined_ids = combined
+            .iter()
+            .map(|combined| combined.combined_id)
+            .collect::<HashSet<_>>();
+        let combined_images = combined
+            .iter()
+            .map(|combined| combined.image_id)
+            .collect::<HashSet<_>>();
+
+        let resources = self.shader_resources()?;
+        let lists = [
+            (BindingClass::UniformBuffer, resources.uniform_buffers()?),
+            (BindingClass::StorageBuffer, resources.storage_buffers()?),
+            (BindingClass::Texture, resources.sampled_images()?),
+            (BindingClass::Texture, resources.separate_images()?),
+            (BindingClass::Image, resources.storage_images()?),
+        ];
+
+        let mut entries = Vec::new();
+        let mut image_types = HashMap::new();
+        for (class, list) in lists {
+            for resource in list {
+                if combined_images.contains(&resource.id) {
+                    image_types.insert(resource.id, resource.type_id);
+                }
+                if combined_ids.contains(&resource.id) || combined_images.contains(&resource.id) {
+                    continue;
+                }
+
+                entries.push(BindingResource {
+                    class,
+                    id: resource.id,
+                    name: resource.name,
+                    source: resource.id,
+                    sampler: None,
+                    type_id: Some(resource.type_id),
+                });
+            }
+        }
+
+        for combined in combined.iter() {
+            entries.push(BindingResource {
+                class: BindingClass::Texture,
+                id: combined.combined_id,
+                name: self.name_of(combined.combined_id),
+                source: combined.image_id,
+                sampler: Some(combined.sampler_id),
+                type_id: image_types.get(&combined.image_id).copied(),
+            });
+        }
+
+        return Ok(entries);
+    }
+}
+
+/// A resource bound to GL binding points.
+struct BindingResource {
+    class: BindingClass,
+    /// Id of the resource's variable.
+    id: u32,
+    name: String,
+    /// Variable holding the Vulkan descriptor set and binding of the resource.
+    source: u32,
+    /// Separate sampler of a combined image sampler.
+    sampler: Option<u32>,
+    /// Type of the resource, which for combined image samplers is the type of their image,
+    /// if it's reflected.
+    type_id: Option<u32>,
+}
@@ -1,21 +1,68 @@
-use super::{Compiler, GenericCompiler};
-use crate::{sys, Context, Result};
+use super::{BorrowedContext, Compiler, GenericCompiler, ParsedIr};
+use crate::{Backend, CompilerOption, Context, Result};
 use semver::Version;
+use std::{
+    borrow::Borrow,
+    ffi::CStr,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
 
 /// High Level Language compiler.
-pub struct HlslCompiler<'a> {
-    inner: GenericCompiler<'a>,
+pub struct HlslCompiler<'a, C = &'a mut Context> {
+    inner: GenericCompiler<'a, C>,
 }
 
 impl<'a> HlslCompiler<'a> {
     pub fn new(ctx: &'a mut Context, words: &[u32]) -> Result<Self> {
         return Ok(Self {
-            inner: GenericCompiler::new(ctx, sys::spvc_backend::SPVC_BACKEND_HLSL, words)?,
+            inner: GenericCompiler::new(ctx, Backend::Hlsl, words)?,
         });
     }
+}
+
+impl<'a> HlslCompiler<'a, &'a Context> {
+    /// Creates a new compiler working on a copy of the already parsed module, see [`ParsedIr::compiler`].
+    pub fn from_parsed_ir<C: Borrow<Context>>(parsed_ir: &'a ParsedIr<'_, C>) -> Result<Self> {
+        return Ok(Self {
+            inner: parsed_ir.compiler(Backend::Hlsl)?,
+        });
+    }
+}
+
+impl<'a, C: BorrowedContext<'a>> HlslCompiler<'a, C> {
+    /// Compiles the program, returning a reference to the context's resulting C string,
+    /// see [`GenericCompiler::raw_compile`].
+    #[inline]
+    pub fn raw_compile(self) -> Result<&'a CStr> {
+        self.inner.raw_compile()
+    }
+}
+
+impl HlslCompiler<'static, Context> {
+    /// Creates a new compiler that owns its context, see [`GenericCompiler::new_owned`].
+    ///
+    /// The returned compiler has no `raw_compile`, as its result would be freed along with the context;
+    /// use [`GenericCompiler::raw_compile_mut`] or [`Compiler::compile`] instead.
+    pub fn new_owned(ctx: Context, words: &[u32]) -> Result<Self> {
+        return Ok(Self {
+            inner: GenericCompiler::new_owned(ctx, Backend::Hlsl, words)?,
+        });
+    }
+}
+
+impl HlslCompiler<'static, Rc<Context>> {
+    /// Creates a new compiler sharing the context of the already parsed module, see [`ParsedIr::shared_compiler`].
+    pub fn from_shared_parsed_ir(parsed_ir: &ParsedIr<'static, Rc<Context>>) -> Result<Self> {
+        return Ok(Self {
+            inner: parsed_ir.shared_compiler(Backend::Hlsl)?,
+        });
+    }
+}
 
+impl<'a, C: Borrow<Context>> HlslCompiler<'a, C> {
     #[inline]
-    pub fn into_generic(self) -> GenericCompiler<'a> {
+    pub fn into_generic(self) -> GenericCompiler<'a, C> {
         self.inner
     }
 
@@ -25,24 +72,15 @@ impl<'a> HlslCompiler<'a> {
 
     pub fn shader_model(self, major: u32, minor: u32) -> Result<Self> {
         let version = (10 * major) + minor;
-        self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_HLSL_SHADER_MODEL,
-            version,
-        )
+        self.set_uint(CompilerOption::HlslShaderModel, version)
     }
 
     pub fn point_size_compat(self, point_size_compat: bool) -> Result<Self> {
-        self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_HLSL_POINT_SIZE_COMPAT,
-            point_size_compat,
-        )
+        self.set_bool(CompilerOption::HlslPointSizeCompat, point_size_compat)
     }
 
     pub fn point_coord_compat(self, point_coord_compat: bool) -> Result<Self> {
-        self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_HLSL_POINT_COORD_COMPAT,
-            point_coord_compat,
-        )
+        self.set_bool(CompilerOption::HlslPointCoordCompat, point_coord_compat)
     }
 
     pub fn support_nonzero_base_vertex_base_instance(
@@ -50,14 +88,14 @@ impl<'a> HlslCompiler<'a> {
         support_nonzero_base_vertex_base_instance: bool,
     ) -> Result<Self> {
         self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_HLSL_SUPPORT_NONZERO_BASE_VERTEX_BASE_INSTANCE,
+            CompilerOption::HlslSupportNonzeroBaseVertexBaseInstance,
             support_nonzero_base_vertex_base_instance,
         )
     }
 
     pub fn force_storage_buffer_as_uav(self, force_storage_buffer_as_uav: bool) -> Result<Self> {
         self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_HLSL_FORCE_STORAGE_BUFFER_AS_UAV,
+            CompilerOption::HlslForceStorageBufferAsUav,
             force_storage_buffer_as_uav,
         )
     }
@@ -67,16 +105,13 @@ impl<'a> HlslCompiler<'a> {
         nonwritable_uav_texture_as_srv: bool,
     ) -> Result<Self> {
         self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_HLSL_NONWRITABLE_UAV_TEXTURE_AS_SRV,
+            CompilerOption::HlslNonwritableUavTextureAsSrv,
             nonwritable_uav_texture_as_srv,
         )
     }
 
     pub fn enable_16bit_types(self, enable_16bit_types: bool) -> Result<Self> {
-        self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_HLSL_ENABLE_16BIT_TYPES,
-            enable_16bit_types,
-        )
+        self.set_bool(CompilerOption::HlslEnable16bitTypes, enable_16bit_types)
     }
 
     pub fn flatten_matrix_vertex_input_semantics(
@@ -84,34 +119,47 @@ impl<'a> HlslCompiler<'a> {
         flatten_matrix_vertex_input_semantics: bool,
     ) -> Result<Self> {
         self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_HLSL_FLATTEN_MATRIX_VERTEX_INPUT_SEMANTICS,
+            CompilerOption::HlslFlattenMatrixVertexInputSemantics,
             flatten_matrix_vertex_input_semantics,
         )
     }
 }
 
-impl<'a> From<HlslCompiler<'a>> for GenericCompiler<'a> {
+impl<'a, C> Deref for HlslCompiler<'a, C> {
+    type Target = GenericCompiler<'a, C>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, C> DerefMut for HlslCompiler<'a, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<'a, C> From<HlslCompiler<'a, C>> for GenericCompiler<'a, C> {
     #[inline]
-    fn from(value: HlslCompiler<'a>) -> Self {
+    fn from(value: HlslCompiler<'a, C>) -> Self {
         value.inner
     }
 }
 
-impl<'a> Compiler<'a> for HlslCompiler<'a> {
-    fn raw_compile(self) -> Result<&'a std::ffi::CStr> {
-        self.inner.raw_compile()
+impl<'a, C: Borrow<Context>> Compiler<'a> for HlslCompiler<'a, C> {
+    #[inline]
+    fn compile(self) -> Result<String> {
+        self.inner.compile()
     }
 
-    fn set_uint(
-        mut self,
-        option: sys::spvc_compiler_option,
-        value: std::ffi::c_uint,
-    ) -> Result<Self> {
+    fn set_uint(mut self, option: CompilerOption, value: std::ffi::c_uint) -> Result<Self> {
         self.inner = self.inner.set_uint(option, value)?;
         return Ok(self);
     }
 
-    fn set_bool(mut self, option: sys::spvc_compiler_option, value: bool) -> Result<Self> {
+    fn set_bool(mut self, option: CompilerOption, value: bool) -> Result<Self> {
         self.inner = self.inner.set_bool(option, value)?;
         return Ok(self);
     }
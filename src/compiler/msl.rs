@@ -1,21 +1,68 @@
-use super::{Compiler, GenericCompiler};
-use crate::{sys, Context, Result};
+use super::{BorrowedContext, Compiler, GenericCompiler, ParsedIr};
+use crate::{sys, Backend, CompilerOption, Context, MslPlatform, Result};
 use semver::Version;
+use std::{
+    borrow::Borrow,
+    ffi::CStr,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
 
 /// Metal Shading Language compiler.
-pub struct MslCompiler<'a> {
-    inner: GenericCompiler<'a>,
+pub struct MslCompiler<'a, C = &'a mut Context> {
+    inner: GenericCompiler<'a, C>,
 }
 
 impl<'a> MslCompiler<'a> {
     pub fn new(ctx: &'a mut Context, words: &[u32]) -> Result<Self> {
         return Ok(Self {
-            inner: GenericCompiler::new(ctx, sys::spvc_backend::SPVC_BACKEND_MSL, words)?,
+            inner: GenericCompiler::new(ctx, Backend::Msl, words)?,
         });
     }
+}
+
+impl<'a> MslCompiler<'a, &'a Context> {
+    /// Creates a new compiler working on a copy of the already parsed module, see [`ParsedIr::compiler`].
+    pub fn from_parsed_ir<C: Borrow<Context>>(parsed_ir: &'a ParsedIr<'_, C>) -> Result<Self> {
+        return Ok(Self {
+            inner: parsed_ir.compiler(Backend::Msl)?,
+        });
+    }
+}
 
+impl<'a, C: BorrowedContext<'a>> MslCompiler<'a, C> {
+    /// Compiles the program, returning a reference to the context's resulting C string,
+    /// see [`GenericCompiler::raw_compile`].
     #[inline]
-    pub fn into_generic(self) -> GenericCompiler<'a> {
+    pub fn raw_compile(self) -> Result<&'a CStr> {
+        self.inner.raw_compile()
+    }
+}
+
+impl MslCompiler<'static, Context> {
+    /// Creates a new compiler that owns its context, see [`GenericCompiler::new_owned`].
+    ///
+    /// The returned compiler has no `raw_compile`, as its result would be freed along with the context;
+    /// use [`GenericCompiler::raw_compile_mut`] or [`Compiler::compile`] instead.
+    pub fn new_owned(ctx: Context, words: &[u32]) -> Result<Self> {
+        return Ok(Self {
+            inner: GenericCompiler::new_owned(ctx, Backend::Msl, words)?,
+        });
+    }
+}
+
+impl MslCompiler<'static, Rc<Context>> {
+    /// Creates a new compiler sharing the context of the already parsed module, see [`ParsedIr::shared_compiler`].
+    pub fn from_shared_parsed_ir(parsed_ir: &ParsedIr<'static, Rc<Context>>) -> Result<Self> {
+        return Ok(Self {
+            inner: parsed_ir.shared_compiler(Backend::Msl)?,
+        });
+    }
+}
+
+impl<'a, C: Borrow<Context>> MslCompiler<'a, C> {
+    #[inline]
+    pub fn into_generic(self) -> GenericCompiler<'a, C> {
         self.inner
     }
 
@@ -25,36 +72,30 @@ impl<'a> MslCompiler<'a> {
 
     pub fn version(self, major: u32, minor: u32) -> Result<Self> {
         let version = (10000 * major) + (100 * minor);
-        return self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_VERSION,
-            version,
-        );
+        return self.set_uint(CompilerOption::MslVersion, version);
     }
 
     pub fn texel_buffer_texture_width(self, texel_buffer_texture_width: u32) -> Result<Self> {
         return self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_TEXEL_BUFFER_TEXTURE_WIDTH,
+            CompilerOption::MslTexelBufferTextureWidth,
             texel_buffer_texture_width,
         );
     }
 
     pub fn aux_buffer_index(self, aux_buffer_index: u32) -> Result<Self> {
-        return self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_AUX_BUFFER_INDEX,
-            aux_buffer_index,
-        );
+        return self.set_uint(CompilerOption::MslAuxBufferIndex, aux_buffer_index);
     }
 
     pub fn indirect_params_buffer_index(self, indirect_params_buffer_index: u32) -> Result<Self> {
         return self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_INDIRECT_PARAMS_BUFFER_INDEX,
+            CompilerOption::MslIndirectParamsBufferIndex,
             indirect_params_buffer_index,
         );
     }
 
     pub fn shader_output_buffer_index(self, shader_output_buffer_index: u32) -> Result<Self> {
         return self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_SHADER_OUTPUT_BUFFER_INDEX,
+            CompilerOption::MslShaderOutputBufferIndex,
             shader_output_buffer_index,
         );
     }
@@ -64,7 +105,7 @@ impl<'a> MslCompiler<'a> {
         shader_patch_output_buffer_index: u32,
     ) -> Result<Self> {
         return self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_SHADER_PATCH_OUTPUT_BUFFER_INDEX,
+            CompilerOption::MslShaderPatchOutputBufferIndex,
             shader_patch_output_buffer_index,
         );
     }
@@ -74,42 +115,42 @@ impl<'a> MslCompiler<'a> {
         shader_tess_factor_output_buffer_index: u32,
     ) -> Result<Self> {
         return self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_SHADER_TESS_FACTOR_OUTPUT_BUFFER_INDEX,
+            CompilerOption::MslShaderTessFactorOutputBufferIndex,
             shader_tess_factor_output_buffer_index,
         );
     }
 
     pub fn shader_input_workgroup_index(self, shader_input_workgroup_index: u32) -> Result<Self> {
         return self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_SHADER_INPUT_WORKGROUP_INDEX,
+            CompilerOption::MslShaderInputWorkgroupIndex,
             shader_input_workgroup_index,
         );
     }
 
     pub fn enable_point_size_builtin(self, enable_point_size_builtin: bool) -> Result<Self> {
         return self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_ENABLE_POINT_SIZE_BUILTIN,
+            CompilerOption::MslEnablePointSizeBuiltin,
             enable_point_size_builtin,
         );
     }
 
     pub fn disable_rasterization(self, disable_rasterization: bool) -> Result<Self> {
         return self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_DISABLE_RASTERIZATION,
+            CompilerOption::MslDisableRasterization,
             disable_rasterization,
         );
     }
 
     pub fn capture_output_to_buffer(self, capture_output_to_buffer: bool) -> Result<Self> {
         return self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_CAPTURE_OUTPUT_TO_BUFFER,
+            CompilerOption::MslCaptureOutputToBuffer,
             capture_output_to_buffer,
         );
     }
 
     pub fn swizzle_texture_samples(self, swizzle_texture_samples: bool) -> Result<Self> {
         return self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_SWIZZLE_TEXTURE_SAMPLES,
+            CompilerOption::MslSwizzleTextureSamples,
             swizzle_texture_samples,
         );
     }
@@ -119,7 +160,7 @@ impl<'a> MslCompiler<'a> {
         pad_fragment_output_components: bool,
     ) -> Result<Self> {
         return self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_PAD_FRAGMENT_OUTPUT_COMPONENTS,
+            CompilerOption::MslPadFragmentOutputComponents,
             pad_fragment_output_components,
         );
     }
@@ -129,55 +170,65 @@ impl<'a> MslCompiler<'a> {
         tess_domain_origin_lower_left: bool,
     ) -> Result<Self> {
         return self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_TESS_DOMAIN_ORIGIN_LOWER_LEFT,
+            CompilerOption::MslTessDomainOriginLowerLeft,
             tess_domain_origin_lower_left,
         );
     }
 
-    pub fn platform(self, platform: sys::spvc_msl_platform) -> Result<Self> {
+    pub fn platform(self, platform: MslPlatform) -> Result<Self> {
         return self.set_uint(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_PLATFORM,
-            platform as u32,
+            CompilerOption::MslPlatform,
+            sys::spvc_msl_platform::from(platform) as u32,
         );
     }
 
     pub fn argument_buffers(self, argument_buffers: bool) -> Result<Self> {
-        return self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_ARGUMENT_BUFFERS,
-            argument_buffers,
-        );
+        return self.set_bool(CompilerOption::MslArgumentBuffers, argument_buffers);
     }
 
     pub fn texture_buffer_native(self, texture_buffer_native: bool) -> Result<Self> {
         return self.set_bool(
-            sys::spvc_compiler_option::SPVC_COMPILER_OPTION_MSL_TEXTURE_BUFFER_NATIVE,
+            CompilerOption::MslTextureBufferNative,
             texture_buffer_native,
         );
     }
 }
 
-impl<'a> From<MslCompiler<'a>> for GenericCompiler<'a> {
+impl<'a, C> Deref for MslCompiler<'a, C> {
+    type Target = GenericCompiler<'a, C>;
+
     #[inline]
-    fn from(value: MslCompiler<'a>) -> Self {
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, C> DerefMut for MslCompiler<'a, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<'a, C> From<MslCompiler<'a, C>> for GenericCompiler<'a, C> {
+    #[inline]
+    fn from(value: MslCompiler<'a, C>) -> Self {
         value.inner
     }
 }
 
-impl<'a> Compiler<'a> for MslCompiler<'a> {
-    fn raw_compile(self) -> Result<&'a std::ffi::CStr> {
-        self.inner.raw_compile()
+impl<'a, C: Borrow<Context>> Compiler<'a> for MslCompiler<'a, C> {
+    #[inline]
+    fn compile(self) -> Result<String> {
+        self.inner.compile()
     }
 
-    fn set_uint(
-        mut self,
-        option: sys::spvc_compiler_option,
-        value: std::ffi::c_uint,
-    ) -> Result<Self> {
+    fn set_uint(mut self, option: CompilerOption, value: std::ffi::c_uint) -> Result<Self> {
         self.inner = self.inner.set_uint(option, value)?;
         return Ok(self);
     }
 
-    fn set_bool(mut self, option: sys::spvc_compiler_option, value: bool) -> Result<Self> {
+    fn set_bool(mut self, option: CompilerOption, value: bool) -> Result<Self> {
         self.inner = self.inner.set_bool(option, value)?;
         return Ok(self);
     }
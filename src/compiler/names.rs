@@ -0,0 +1,66 @@
+use super::GenericCompiler;
+use crate::{sys, Context, Error, Result};
+use std::{
+    borrow::Borrow,
+    ffi::{CStr, CString},
+};
+
+impl<'a, C: Borrow<Context>> GenericCompiler<'a, C> {
+    /// Returns the name of the id, which is empty if the module has no debug information for it.
+    pub fn name_of(&self, id: u32) -> String {
+        unsafe {
+            let name = sys::spvc_compiler_get_name(self.compiler, id);
+            return CStr::from_ptr(name).to_string_lossy().into_owned();
+        }
+    }
+
+    /// Sets the name of the id, as emitted in the output source.
+    pub fn set_name(&mut self, id: u32, name: &str) -> Result<()> {
+        let name = CString::new(name)?;
+        unsafe {
+            sys::spvc_compiler_set_name(self.compiler, id, name.as_ptr());
+        }
+        return Ok(());
+    }
+
+    /// Returns the name of the struct's member, which is empty if the module has no debug information for it.
+    pub fn member_name_of(&self, type_id: u32, member: u32) -> String {
+        unsafe {
+            let name = sys::spvc_compiler_get_member_name(self.compiler, type_id, member);
+            return CStr::from_ptr(name).to_string_lossy().into_owned();
+        }
+    }
+
+    /// Sets the name of the struct's member, as emitted in the output source.
+    pub fn set_member_name(&mut self, type_id: u32, member: u32, name: &str) -> Result<()> {
+        let name = CString::new(name)?;
+        unsafe {
+            sys::spvc_compiler_set_member_name(self.compiler, type_id, member, name.as_ptr());
+        }
+        return Ok(());
+    }
+
+    /// Returns the name the block of the variable will be declared with in the output source,
+    /// which might differ from its type's name if it had to be made unique while compiling.
+    ///
+    /// Fails if the id isn't a variable.
+    pub fn remapped_declared_block_name(&self, id: u32) -> Result<String> {
+        // SPIRV-Cross doesn't bounds-check the id before looking it up,
+        // but does reject ids in bounds that aren't variables
+        if id >= unsafe { sys::spvc_compiler_get_current_id_bound(self.compiler) } {
+            return Err(Error {
+                code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                err_msg: Some(format!("Id {id} is out of bounds.")),
+            });
+        }
+
+        unsafe {
+            let name = sys::spvc_compiler_get_remapped_declared_block_name(self.compiler, id);
+            if name.is_null() {
+                self.ctx
+                    .get_error(sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT)?;
+            }
+            return Ok(CStr::from_ptr(name).to_string_lossy().into_owned());
+        }
+    }
+}
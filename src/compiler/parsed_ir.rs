@@ -0,0 +1,99 @@
+use super::GenericCompiler;
+use crate::{sys, Backend, Context, Result};
+use std::{borrow::Borrow, marker::PhantomData, mem::MaybeUninit, rc::Rc};
+
+/// A parsed SPIR-V module, from which any number of compilers can be created without parsing it again.
+///
+/// `C` is how the module holds its [`Context`]: borrowed by default, or shared through an [`Rc`]
+/// when created with [`ParsedIr::new_shared`].
+pub struct ParsedIr<'a, C = &'a mut Context> {
+    pub inner: sys::spvc_parsed_ir,
+    ctx: C,
+    lifetime: PhantomData<&'a ()>,
+}
+
+impl<'a> ParsedIr<'a> {
+    /// Parses the SPIR-V module.
+    pub fn new(ctx: &'a mut Context, words: &[u32]) -> Result<Self> {
+        return Ok(Self {
+            inner: unsafe { parse(ctx, words)? },
+            ctx,
+            lifetime: PhantomData,
+        });
+    }
+}
+
+impl ParsedIr<'static, Rc<Context>> {
+    /// Parses the SPIR-V module into a context shared with the compilers created from it,
+    /// which don't borrow anything and keep the context alive as long as any of them.
+    pub fn new_shared(ctx: Context, words: &[u32]) -> Result<Self> {
+        return Ok(Self {
+            inner: unsafe { parse(&ctx, words)? },
+            ctx: Rc::new(ctx),
+            lifetime: PhantomData,
+        });
+    }
+
+    /// Creates a compiler working on a copy of the module, sharing the context with the module and the
+    /// other compilers instead of borrowing it.
+    #[inline]
+    pub fn shared_compiler(
+        &self,
+        backend: Backend,
+    ) -> Result<GenericCompiler<'static, Rc<Context>>> {
+        unsafe {
+            return GenericCompiler::from_raw_parsed_ir(
+                Rc::clone(&self.ctx),
+                backend,
+                self.inner,
+                sys::spvc_capture_mode::SPVC_CAPTURE_MODE_COPY,
+            );
+        }
+    }
+}
+
+impl<'a, C: Borrow<Context>> ParsedIr<'a, C> {
+    /// Creates a compiler working on a copy of the module, leaving it available for other compilers.
+    ///
+    /// Any number of these compilers can be alive at once. Since they share the context,
+    /// they can't be sent to another thread.
+    #[inline]
+    pub fn compiler(&self, backend: Backend) -> Result<GenericCompiler<'_, &Context>> {
+        unsafe {
+            return GenericCompiler::from_raw_parsed_ir(
+                self.ctx.borrow(),
+                backend,
+                self.inner,
+                sys::spvc_capture_mode::SPVC_CAPTURE_MODE_COPY,
+            );
+        }
+    }
+
+    /// Creates a compiler that takes ownership of the module, avoiding a copy.
+    #[inline]
+    pub fn into_compiler(self, backend: Backend) -> Result<GenericCompiler<'a, C>> {
+        unsafe {
+            return GenericCompiler::from_raw_parsed_ir(
+                self.ctx,
+                backend,
+                self.inner,
+                sys::spvc_capture_mode::SPVC_CAPTURE_MODE_TAKE_OWNERSHIP,
+            );
+        }
+    }
+}
+
+/// Parses the SPIR-V module into an IR allocated by `ctx`.
+pub(super) unsafe fn parse(ctx: &Context, words: &[u32]) -> Result<sys::spvc_parsed_ir> {
+    let mut parsed_ir = MaybeUninit::uninit();
+    ctx.get_error(sys::spvc_context_parse_spirv(
+        ctx.inner,
+        words.as_ptr(),
+        words.len(),
+        parsed_ir.as_mut_ptr(),
+    ))?;
+    return Ok(parsed_ir.assume_init());
+}
+
+// Like compilers, the parsed module is only ever used through its context, so it can be sent along with it.
+unsafe impl<'a, C: Send> Send for ParsedIr<'a, C> {}
@@ -0,0 +1,249 @@
+use super::GenericCompiler;
+use crate::{sys, BuiltIn, BuiltinResourceType, Context, ResourceType, Result};
+use std::{borrow::Borrow, ffi::CStr, marker::PhantomData, mem::MaybeUninit};
+
+/// A resource declared by the shader.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Resource {
+    /// Id of the resource's variable.
+    pub id: u32,
+    /// Id of the resource's base type, ignoring array dimensions.
+    pub base_type_id: u32,
+    /// Id of the resource's type.
+    pub type_id: u32,
+    /// Name of the resource, which might be empty if the shader has no debug information.
+    pub name: String,
+}
+
+/// A builtin variable used as a stage input or output.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BuiltinResource {
+    /// Builtin the variable is decorated with.
+    pub builtin: BuiltIn,
+    /// Id of the builtin's value type.
+    pub value_type_id: u32,
+    /// Variable that holds the builtin.
+    ///
+    /// For builtins declared inside a block (like `gl_PerVertex`), this is the block variable.
+    pub resource: Resource,
+}
+
+/// Reflection over the resources declared by a shader.
+pub struct ShaderResources<'a> {
+    pub inner: sys::spvc_resources,
+    ctx: &'a Context,
+}
+
+/// Set of interface variables, allocated by the compiler's context.
+///
+/// The set borrows the compiler, since owned compilers free it along with their context.
+#[derive(Clone, Copy)]
+pub struct InterfaceVariables<'a> {
+    pub inner: sys::spvc_set,
+    _ctx: PhantomData<&'a Context>,
+}
+
+impl<'a, C: Borrow<Context>> GenericCompiler<'a, C> {
+    /// Returns the resources declared by the shader.
+    pub fn shader_resources(&self) -> Result<ShaderResources<'_>> {
+        let mut resources = MaybeUninit::uninit();
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_create_shader_resources(
+                    self.compiler,
+                    resources.as_mut_ptr(),
+                ))?;
+
+            return Ok(ShaderResources {
+                inner: resources.assume_init(),
+                ctx: &self.ctx,
+            });
+        }
+    }
+
+    /// Returns the interface variables statically used by the selected entry point.
+    pub fn active_interface_variables(&self) -> Result<InterfaceVariables<'_>> {
+        let mut set = MaybeUninit::uninit();
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_get_active_interface_variables(
+                    self.compiler,
+                    set.as_mut_ptr(),
+                ))?;
+
+            return Ok(InterfaceVariables {
+                inner: set.assume_init(),
+                _ctx: PhantomData,
+            });
+        }
+    }
+
+    /// Returns the resources declared by the shader, restricted to the specified variables.
+    pub fn shader_resources_for(
+        &self,
+        variables: &InterfaceVariables<'_>,
+    ) -> Result<ShaderResources<'_>> {
+        let mut resources = MaybeUninit::uninit();
+        unsafe {
+            self.ctx.get_error(
+                sys::spvc_compiler_create_shader_resources_for_active_variables(
+                    self.compiler,
+                    resources.as_mut_ptr(),
+                    variables.inner,
+                ),
+            )?;
+
+            return Ok(ShaderResources {
+                inner: resources.assume_init(),
+                ctx: &self.ctx,
+            });
+        }
+    }
+
+    /// Returns the resources statically used by the selected entry point.
+    pub fn active_shader_resources(&self) -> Result<ShaderResources<'_>> {
+        return self.shader_resources_for(&self.active_interface_variables()?);
+    }
+
+    /// Restricts the variables declared in the emitted source to the ones statically used by the selected entry point.
+    pub fn enable_active_interface_variables(&mut self) -> Result<()> {
+        let variables = self.active_interface_variables()?.inner;
+        unsafe {
+            return self
+                .ctx
+                .get_error(sys::spvc_compiler_set_enabled_interface_variables(
+                    self.compiler,
+                    variables,
+                ));
+        }
+    }
+}
+
+macro_rules! resource_lists {
+    ($($(#[$meta:meta])* $name:ident => $ty:ident),+ $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[inline]
+            pub fn $name(&self) -> Result<Vec<Resource>> {
+                self.resource_list(ResourceType::$ty)
+            }
+        )+
+    };
+}
+
+impl<'a> ShaderResources<'a> {
+    /// Returns the resources of the specified type.
+    pub fn resource_list(&self, ty: ResourceType) -> Result<Vec<Resource>> {
+        let mut list = MaybeUninit::uninit();
+        let mut len = MaybeUninit::uninit();
+
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_resources_get_resource_list_for_type(
+                    self.inner,
+                    ty.into(),
+                    list.as_mut_ptr(),
+                    len.as_mut_ptr(),
+                ))?;
+
+            let (list, len) = (list.assume_init(), len.assume_init());
+            if len == 0 {
+                return Ok(Vec::new());
+            }
+
+            return Ok(core::slice::from_raw_parts(list, len)
+                .iter()
+                .map(|resource| Resource::from_raw(resource))
+                .collect());
+        }
+    }
+
+    /// Returns the builtin variables of the specified type.
+    ///
+    /// Builtins unknown to the bundled SPIR-V headers are skipped.
+    pub fn builtin_resource_list(&self, ty: BuiltinResourceType) -> Result<Vec<BuiltinResource>> {
+        let mut list = MaybeUninit::uninit();
+        let mut len = MaybeUninit::uninit();
+
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_resources_get_builtin_resource_list_for_type(
+                    self.inner,
+                    ty.into(),
+                    list.as_mut_ptr(),
+                    len.as_mut_ptr(),
+                ))?;
+
+            let (list, len) = (list.assume_init(), len.assume_init());
+            if len == 0 {
+                return Ok(Vec::new());
+            }
+
+            // Fields are read through raw pointers, since newer builtins aren't valid `sys::SpvBuiltIn`s
+            return Ok((0..len)
+                .map(|i| list.add(i))
+                .filter_map(|builtin| {
+                    let raw = core::ptr::addr_of!((*builtin).builtin).cast::<u32>().read();
+                    Some(BuiltinResource {
+                        builtin: BuiltIn::try_from(raw).ok()?,
+                        value_type_id: (*builtin).value_type_id,
+                        resource: Resource::from_raw(&(*builtin).resource),
+                    })
+                })
+                .collect());
+        }
+    }
+
+    /// Returns the builtin stage inputs used by the shader.
+    #[inline]
+    pub fn builtin_inputs(&self) -> Result<Vec<BuiltinResource>> {
+        self.builtin_resource_list(BuiltinResourceType::StageInput)
+    }
+
+    /// Returns the builtin stage outputs used by the shader.
+    #[inline]
+    pub fn builtin_outputs(&self) -> Result<Vec<BuiltinResource>> {
+        self.builtin_resource_list(BuiltinResourceType::StageOutput)
+    }
+
+    resource_lists! {
+        /// Returns the uniform buffers declared by the shader.
+        uniform_buffers => UniformBuffer,
+        /// Returns the storage buffers declared by the shader.
+        storage_buffers => StorageBuffer,
+        /// Returns the (non-builtin) stage inputs declared by the shader.
+        stage_inputs => StageInput,
+        /// Returns the (non-builtin) stage outputs declared by the shader.
+        stage_outputs => StageOutput,
+        /// Returns the subpass inputs declared by the shader.
+        subpass_inputs => SubpassInput,
+        /// Returns the storage images declared by the shader.
+        storage_images => StorageImage,
+        /// Returns the combined image samplers declared by the shader.
+        sampled_images => SampledImage,
+        /// Returns the atomic counters declared by the shader.
+        atomic_counters => AtomicCounter,
+        /// Returns the push constant buffers declared by the shader.
+        push_constant_buffers => PushConstant,
+        /// Returns the separate images declared by the shader.
+        separate_images => SeparateImage,
+        /// Returns the separate samplers declared by the shader.
+        separate_samplers => SeparateSamplers,
+        /// Returns the acceleration structures declared by the shader.
+        acceleration_structures => AccelerationStructure,
+    }
+}
+
+impl Resource {
+    unsafe fn from_raw(resource: &sys::spvc_reflected_resource) -> Self {
+        return Self {
+            id: resource.id,
+            base_type_id: resource.base_type_id,
+            type_id: resource.type_id,
+            name: match resource.name.is_null() {
+                true => String::new(),
+                false => CStr::from_ptr(resource.name).to_string_lossy().into_owned(),
+            },
+        };
+    }
+}
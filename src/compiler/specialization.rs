@@ -0,0 +1,174 @@
+use super::GenericCompiler;
+use crate::{sys, BaseType, Context, Error, Result};
+use std::{borrow::Borrow, mem::MaybeUninit};
+
+/// A specialization constant of the module.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecializationConstant {
+    pub id: u32,
+    /// Id of the constant as seen by the API (the `SpecId` decoration).
+    pub constant_id: u32,
+    pub type_id: u32,
+    pub name: String,
+    /// Value of the constant, which is baked into the emitted source on backends without runtime specialization.
+    pub value: ScalarValue,
+}
+
+/// Value of a scalar specialization constant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScalarValue {
+    Bool(bool),
+    I8(i8),
+    U8(u8),
+    I16(i16),
+    U16(u16),
+    I32(i32),
+    U32(u32),
+    /// Raw bits of an IEEE 754 half-precision float.
+    F16(u16),
+    F32(f32),
+    F64(f64),
+    /// Value of a type the C API can't access (like 64-bit integers), which can't be read nor overridden.
+    Unsupported {
+        type_id: u32,
+    },
+}
+
+impl<'a, C: Borrow<Context>> GenericCompiler<'a, C> {
+    /// Returns the specialization constants declared by the module, along with their current values.
+    pub fn specialization_constants(&self) -> Result<Vec<SpecializationConstant>> {
+        let mut constants = MaybeUninit::uninit();
+        let mut len = MaybeUninit::uninit();
+
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_get_specialization_constants(
+                    self.compiler,
+                    constants.as_mut_ptr(),
+                    len.as_mut_ptr(),
+                ))?;
+
+            let (constants, len) = (constants.assume_init(), len.assume_init());
+            if len == 0 {
+                return Ok(Vec::new());
+            }
+
+            return core::slice::from_raw_parts(constants, len)
+                .iter()
+                .map(|constant| {
+                    let handle = self.constant_handle(constant.id)?;
+                    let type_id = sys::spvc_constant_get_type(handle);
+
+                    Ok(SpecializationConstant {
+                        id: constant.id,
+                        constant_id: constant.constant_id,
+                        type_id,
+                        name: self.name_of(constant.id),
+                        value: self.scalar_value(handle, type_id)?,
+                    })
+                })
+                .collect();
+        }
+    }
+
+    /// Overrides the value of the specialization constant with the specified `constant_id`,
+    /// failing if the value's type doesn't match the constant's.
+    pub fn set_specialization_constant(
+        &mut self,
+        constant_id: u32,
+        value: ScalarValue,
+    ) -> Result<()> {
+        let Some(constant) = self
+            .specialization_constants()?
+            .into_iter()
+            .find(|constant| constant.constant_id == constant_id)
+        else {
+            return Err(Error {
+                code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                err_msg: Some(format!(
+                    "Specialization constant with id {constant_id} does not exist."
+                )),
+            });
+        };
+
+        if let ScalarValue::Unsupported { .. } = constant.value {
+            return Err(Error {
+                code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                err_msg: Some(format!(
+                    "Specialization constant with id {constant_id} has an unsupported type."
+                )),
+            });
+        }
+
+        if core::mem::discriminant(&constant.value) != core::mem::discriminant(&value) {
+            return Err(Error {
+                code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                err_msg: Some(format!(
+                    "Specialization constant with id {constant_id} holds a {:?}, found {value:?}.",
+                    constant.value
+                )),
+            });
+        }
+
+        let handle = self.constant_handle(constant.id)?;
+        unsafe {
+            match value {
+                ScalarValue::Bool(value) => {
+                    sys::spvc_constant_set_scalar_u32(handle, 0, 0, value as u32)
+                }
+                ScalarValue::I8(value) => sys::spvc_constant_set_scalar_i8(handle, 0, 0, value),
+                ScalarValue::U8(value) => sys::spvc_constant_set_scalar_u8(handle, 0, 0, value),
+                ScalarValue::I16(value) => sys::spvc_constant_set_scalar_i16(handle, 0, 0, value),
+                ScalarValue::U16(value) => sys::spvc_constant_set_scalar_u16(handle, 0, 0, value),
+                ScalarValue::I32(value) => sys::spvc_constant_set_scalar_i32(handle, 0, 0, value),
+                ScalarValue::U32(value) => sys::spvc_constant_set_scalar_u32(handle, 0, 0, value),
+                ScalarValue::F16(value) => sys::spvc_constant_set_scalar_fp16(handle, 0, 0, value),
+                ScalarValue::F32(value) => sys::spvc_constant_set_scalar_fp32(handle, 0, 0, value),
+                ScalarValue::F64(value) => sys::spvc_constant_set_scalar_fp64(handle, 0, 0, value),
+                ScalarValue::Unsupported { .. } => unreachable!(),
+            }
+        }
+
+        return Ok(());
+    }
+
+    fn constant_handle(&self, id: u32) -> Result<sys::spvc_constant> {
+        let handle = unsafe { sys::spvc_compiler_get_constant_handle(self.compiler, id) };
+        if handle.is_null() {
+            self.ctx
+                .get_error(sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT)?;
+        }
+        return Ok(handle);
+    }
+
+    unsafe fn scalar_value(&self, handle: sys::spvc_constant, type_id: u32) -> Result<ScalarValue> {
+        let Ok(base_type) = self.type_handle(type_id)?.base_type() else {
+            return Ok(ScalarValue::Unsupported { type_id });
+        };
+
+        return Ok(match base_type {
+            BaseType::Boolean => {
+                ScalarValue::Bool(sys::spvc_constant_get_scalar_u32(handle, 0, 0) != 0)
+            }
+            BaseType::Int8 => ScalarValue::I8(sys::spvc_constant_get_scalar_i8(handle, 0, 0) as i8),
+            BaseType::UInt8 => {
+                ScalarValue::U8(sys::spvc_constant_get_scalar_u8(handle, 0, 0) as u8)
+            }
+            BaseType::Int16 => {
+                ScalarValue::I16(sys::spvc_constant_get_scalar_i16(handle, 0, 0) as i16)
+            }
+            BaseType::UInt16 => {
+                ScalarValue::U16(sys::spvc_constant_get_scalar_u16(handle, 0, 0) as u16)
+            }
+            BaseType::Int32 => ScalarValue::I32(sys::spvc_constant_get_scalar_i32(handle, 0, 0)),
+            BaseType::UInt32 => ScalarValue::U32(sys::spvc_constant_get_scalar_u32(handle, 0, 0)),
+            // Half floats are stored as their raw bits, which the 16-bit integer getter returns as-is.
+            BaseType::Fp16 => {
+                ScalarValue::F16(sys::spvc_constant_get_scalar_u16(handle, 0, 0) as u16)
+            }
+            BaseType::Fp32 => ScalarValue::F32(sys::spvc_constant_get_scalar_fp32(handle, 0, 0)),
+            BaseType::Fp64 => ScalarValue::F64(sys::spvc_constant_get_scalar_fp64(handle, 0, 0)),
+            _ => ScalarValue::Unsupported { type_id },
+        });
+    }
+}
@@ -0,0 +1,335 @@
+use super::GenericCompiler;
+use crate::{
+    sys, AccessQualifier, BaseType, Context, Dim, Error, ImageFormat, Result, StorageClass,
+};
+use std::{borrow::Borrow, mem::MaybeUninit};
+
+/// Size of an array dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArrayDimension {
+    /// Dimension with a literal size. A size of zero denotes a runtime array.
+    Literal(u32),
+    /// Dimension sized by the specialization constant with the specified id.
+    SpecializationConstant(u32),
+}
+
+/// Description of an image type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageType {
+    /// Id of the type returned when sampling or reading the image.
+    pub sampled_type_id: u32,
+    pub dim: Dim,
+    /// Whether the image is a depth image (used with comparison samplers).
+    pub depth: bool,
+    pub arrayed: bool,
+    pub multisampled: bool,
+    /// Whether the image is a storage image (as opposed to a sampled one).
+    pub storage: bool,
+    pub format: ImageFormat,
+    /// Access qualifier of the image, if it declares one.
+    pub access: Option<AccessQualifier>,
+}
+
+/// Memory layout of a buffer block, as declared by the shader.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockLayout {
+    /// Declared size of the block, taking runtime arrays as empty.
+    pub size: usize,
+    pub members: Vec<MemberLayout>,
+}
+
+/// Memory layout of a buffer block's member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MemberLayout {
+    pub offset: u32,
+    /// Declared size of the member, taking runtime arrays as empty.
+    pub size: usize,
+    /// Stride between elements, if the member is an array.
+    pub array_stride: Option<u32>,
+    /// Stride between columns (or rows, if `row_major`), if the member is a matrix.
+    pub matrix_stride: Option<u32>,
+    pub row_major: bool,
+}
+
+/// A view over a SPIR-V type.
+#[derive(Clone, Copy)]
+pub struct Type<'a> {
+    pub inner: sys::spvc_type,
+    compiler: sys::spvc_compiler,
+    ctx: &'a Context,
+}
+
+impl<'a, C: Borrow<Context>> GenericCompiler<'a, C> {
+    /// Returns the type with the specified id.
+    pub fn type_handle(&self, id: u32) -> Result<Type<'_>> {
+        return Type::new(self.compiler, &self.ctx, id);
+    }
+}
+
+impl<'a> Type<'a> {
+    fn new(compiler: sys::spvc_compiler, ctx: &'a Context, id: u32) -> Result<Self> {
+        // SPIRV-Cross doesn't bounds-check the id before looking it up
+        if id >= unsafe { sys::spvc_compiler_get_current_id_bound(compiler) } {
+            return Err(Error {
+                code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                err_msg: Some(format!("Id {id} is out of bounds.")),
+            });
+        }
+
+        let inner = unsafe { sys::spvc_compiler_get_type_handle(compiler, id) };
+        if inner.is_null() {
+            ctx.get_error(sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT)?;
+        }
+
+        return Ok(Self {
+            inner,
+            compiler,
+            ctx,
+        });
+    }
+
+    /// Returns the id of the type, ignoring array dimensions.
+    #[inline]
+    pub fn base_type_id(&self) -> u32 {
+        unsafe { sys::spvc_type_get_base_type_id(self.inner) }
+    }
+
+    #[inline]
+    pub fn base_type(&self) -> Result<BaseType> {
+        unsafe { BaseType::try_from(sys::spvc_type_get_basetype(self.inner)) }
+    }
+
+    #[inline]
+    pub fn bit_width(&self) -> u32 {
+        unsafe { sys::spvc_type_get_bit_width(self.inner) }
+    }
+
+    /// Returns the amount of components of the type (1 for scalars).
+    #[inline]
+    pub fn vector_size(&self) -> u32 {
+        unsafe { sys::spvc_type_get_vector_size(self.inner) }
+    }
+
+    /// Returns the amount of columns of the type (1 for non-matrices).
+    #[inline]
+    pub fn columns(&self) -> u32 {
+        unsafe { sys::spvc_type_get_columns(self.inner) }
+    }
+
+    /// Returns the array dimensions of the type, from innermost to outermost.
+    pub fn array_dimensions(&self) -> Vec<ArrayDimension> {
+        unsafe {
+            let count = sys::spvc_type_get_num_array_dimensions(self.inner);
+            return (0..count)
+                .map(|i| {
+                    let size = sys::spvc_type_get_array_dimension(self.inner, i);
+                    match sys::spvc_type_array_dimension_is_literal(self.inner, i) != 0 {
+                        true => ArrayDimension::Literal(size),
+                        false => ArrayDimension::SpecializationConstant(size),
+                    }
+                })
+                .collect();
+        }
+    }
+
+    #[inline]
+    pub fn is_array(&self) -> bool {
+        unsafe { sys::spvc_type_get_num_array_dimensions(self.inner) > 0 }
+    }
+
+    #[inline]
+    pub fn storage_class(&self) -> Result<StorageClass> {
+        unsafe { StorageClass::try_from(sys::spvc_type_get_storage_class(self.inner)) }
+    }
+
+    /// Returns the ids of the types of the struct's members.
+    pub fn member_type_ids(&self) -> Vec<u32> {
+        unsafe {
+            let count = sys::spvc_type_get_num_member_types(self.inner);
+            return (0..count)
+                .map(|i| sys::spvc_type_get_member_type(self.inner, i))
+                .collect();
+        }
+    }
+
+    /// Returns the types of the struct's members.
+    pub fn members(&self) -> Result<Vec<Type<'a>>> {
+        return self
+            .member_type_ids()
+            .into_iter()
+            .map(|id| Type::new(self.compiler, self.ctx, id))
+            .collect();
+    }
+
+    /// Returns the description of the image, if the type is an image or a sampled image.
+    pub fn image(&self) -> Result<Option<ImageType>> {
+        match self.base_type()? {
+            BaseType::Image | BaseType::SampledImage => unsafe {
+                Ok(Some(ImageType {
+                    sampled_type_id: sys::spvc_type_get_image_sampled_type(self.inner),
+                    dim: Dim::try_from(sys::spvc_type_get_image_dimension(self.inner))?,
+                    depth: sys::spvc_type_get_image_is_depth(self.inner) != 0,
+                    arrayed: sys::spvc_type_get_image_arrayed(self.inner) != 0,
+                    multisampled: sys::spvc_type_get_image_multisampled(self.inner) != 0,
+                    storage: sys::spvc_type_get_image_is_storage(self.inner) != 0,
+                    format: ImageFormat::try_from(sys::spvc_type_get_image_storage_format(
+                        self.inner,
+                    ))?,
+                    access: match sys::spvc_type_get_image_access_qualifier(self.inner) {
+                        sys::SpvAccessQualifier::SpvAccessQualifierMax => None,
+                        access => Some(AccessQualifier::try_from(access)?),
+                    },
+                }))
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the declared size of the struct, taking runtime arrays as empty.
+    pub fn declared_struct_size(&self) -> Result<usize> {
+        let mut size = MaybeUninit::uninit();
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_get_declared_struct_size(
+                    self.compiler,
+                    self.inner,
+                    size.as_mut_ptr(),
+                ))?;
+            return Ok(size.assume_init());
+        }
+    }
+
+    /// Returns the declared size of the struct, with its runtime array holding `array_size` elements.
+    pub fn declared_struct_size_runtime_array(&self, array_size: usize) -> Result<usize> {
+        let mut size = MaybeUninit::uninit();
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_get_declared_struct_size_runtime_array(
+                    self.compiler,
+                    self.inner,
+                    array_size,
+                    size.as_mut_ptr(),
+                ))?;
+            return Ok(size.assume_init());
+        }
+    }
+
+    /// Returns the declared size of the struct's member.
+    pub fn declared_member_size(&self, index: u32) -> Result<usize> {
+        self.check_member(index)?;
+        let mut size = MaybeUninit::uninit();
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_get_declared_struct_member_size(
+                    self.compiler,
+                    self.inner,
+                    index,
+                    size.as_mut_ptr(),
+                ))?;
+            return Ok(size.assume_init());
+        }
+    }
+
+    pub fn member_offset(&self, index: u32) -> Result<u32> {
+        self.check_member(index)?;
+        let mut offset = MaybeUninit::uninit();
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_type_struct_member_offset(
+                    self.compiler,
+                    self.inner,
+                    index,
+                    offset.as_mut_ptr(),
+                ))?;
+            return Ok(offset.assume_init());
+        }
+    }
+
+    /// Returns the array stride of the struct's member, failing if the member isn't an array.
+    pub fn member_array_stride(&self, index: u32) -> Result<u32> {
+        self.check_member(index)?;
+        let mut stride = MaybeUninit::uninit();
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_type_struct_member_array_stride(
+                    self.compiler,
+                    self.inner,
+                    index,
+                    stride.as_mut_ptr(),
+                ))?;
+            return Ok(stride.assume_init());
+        }
+    }
+
+    /// Returns the matrix stride of the struct's member, failing if the member isn't a matrix.
+    pub fn member_matrix_stride(&self, index: u32) -> Result<u32> {
+        self.check_member(index)?;
+        let mut stride = MaybeUninit::uninit();
+        unsafe {
+            self.ctx
+                .get_error(sys::spvc_compiler_type_struct_member_matrix_stride(
+                    self.compiler,
+                    self.inner,
+                    index,
+                    stride.as_mut_ptr(),
+                ))?;
+            return Ok(stride.assume_init());
+        }
+    }
+
+    /// Returns `true` if the struct's member is decorated as `RowMajor`.
+    #[inline]
+    pub fn member_is_row_major(&self, index: u32) -> bool {
+        unsafe {
+            sys::spvc_compiler_has_member_decoration(
+                self.compiler,
+                self.base_type_id(),
+                index,
+                sys::SpvDecoration::SpvDecorationRowMajor,
+            ) != 0
+        }
+    }
+
+    // SPIRV-Cross doesn't bounds-check member indices
+    fn check_member(&self, index: u32) -> Result<()> {
+        let count = unsafe { sys::spvc_type_get_num_member_types(self.inner) };
+        if index >= count {
+            return Err(Error {
+                code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                err_msg: Some(format!(
+                    "Member index {index} is out of bounds for a struct with {count} members."
+                )),
+            });
+        }
+        return Ok(());
+    }
+
+    /// Returns the memory layout of the block.
+    pub fn block_layout(&self) -> Result<BlockLayout> {
+        let members = self
+            .members()?
+            .into_iter()
+            .zip(0..)
+            .map(|(member, i)| {
+                Ok(MemberLayout {
+                    offset: self.member_offset(i)?,
+                    size: self.declared_member_size(i)?,
+                    array_stride: match member.is_array() {
+                        true => Some(self.member_array_stride(i)?),
+                        false => None,
+                    },
+                    matrix_stride: match member.columns() > 1 {
+                        true => Some(self.member_matrix_stride(i)?),
+                        false => None,
+                    },
+                    row_major: self.member_is_row_major(i),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        return Ok(BlockLayout {
+            size: self.declared_struct_size()?,
+            members,
+        });
+    }
+}
@@ -1,13 +1,61 @@
 use crate::{
+    compiler::ParsedIr,
     error::{Error, Result},
     sys,
 };
 use std::{ffi::CStr, mem::MaybeUninit, ops::Deref};
 
 /// Manager of SPIRV-Cross resources
+///
+/// # Thread safety
+///
+/// The underlying context is not internally synchronized, so a [`Context`], along with the compilers
+/// using it, can be moved to another thread, but never shared between threads.
+/// Every operation that allocates on the context goes through either a `&mut Context` or a compiler
+/// using it. Compilers borrowing the context mutably or owning it are [`Send`] but not [`Sync`],
+/// while compilers sharing it, like those created by [`ParsedIr::compiler`], are neither.
+///
+/// ```
+/// use spirvcross::{compiler::GenericCompiler, Context};
+///
+/// fn assert_send<T: Send>() {}
+/// assert_send::<Context>();
+/// assert_send::<GenericCompiler<'static>>();
+/// ```
+///
+/// Contexts can't be shared between threads:
+///
+/// ```compile_fail,E0277
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<spirvcross::Context>();
+/// ```
+///
+/// Neither can compilers:
+///
+/// ```compile_fail,E0277
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<spirvcross::compiler::GenericCompiler<'static>>();
+/// ```
+///
+/// Nor can compilers sharing a context be sent to another thread:
+///
+/// ```compile_fail,E0277
+/// use spirvcross::{compiler::GenericCompiler, Context};
+///
+/// fn assert_send<T: Send>() {}
+/// assert_send::<GenericCompiler<'static, &'static Context>>();
+/// ```
+///
+/// And error callbacks must be [`Send`], since they move along with the context:
+///
+/// ```compile_fail,E0277
+/// let mut context = spirvcross::Context::new().unwrap();
+/// let errors = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+/// context.set_error_callback(move |err| errors.borrow_mut().push(err.to_owned()));
+/// ```
 pub struct Context {
     pub inner: sys::spvc_context,
-    error_callback: Option<Box<dyn FnMut(&CStr)>>,
+    error_callback: Option<Box<dyn Send + FnMut(&CStr)>>,
 }
 
 impl Context {
@@ -26,8 +74,8 @@ impl Context {
     }
 
     /// Sets a new callback function to be called whenever a context returns an error.
-    pub fn set_error_callback<F: 'static + FnMut(&CStr)>(&mut self, f: F) {
-        unsafe extern "C" fn error_callback_wrapper<F: 'static + FnMut(&CStr)>(
+    pub fn set_error_callback<F: 'static + Send + FnMut(&CStr)>(&mut self, f: F) {
+        unsafe extern "C" fn error_callback_wrapper<F: 'static + Send + FnMut(&CStr)>(
             user_data: *mut std::ffi::c_void,
             error: *const std::ffi::c_char,
         ) {
@@ -42,10 +90,16 @@ impl Context {
                 Some(error_callback_wrapper::<F>),
                 f.deref() as *const F as *mut std::ffi::c_void,
             );
-            self.error_callback = Some(f as Box<dyn FnMut(&CStr)>);
+            self.error_callback = Some(f as Box<dyn Send + FnMut(&CStr)>);
         }
     }
 
+    /// Parses the SPIR-V module, so it can be compiled to multiple targets without being parsed again.
+    #[inline]
+    pub fn parse(&mut self, words: &[u32]) -> Result<ParsedIr<'_>> {
+        ParsedIr::new(self, words)
+    }
+
     /// Release all the resources associated to this context.
     ///
     /// This method is safe because the shared reference ensures no other part of the code
@@ -83,5 +137,5 @@ impl Drop for Context {
     }
 }
 
+// The context isn't tied to the thread that created it, and its error callback is `Send`.
 unsafe impl Send for Context {}
-unsafe impl Sync for Context {}
@@ -0,0 +1,758 @@
+use crate::{sys, Error, Result};
+
+/// Declares a Rust enum mirroring a subset of a raw `sys` enum, along with the conversions between both.
+macro_rules! sys_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $sys:ident {
+            $($variant:ident = $sys_variant:ident),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl From<$name> for sys::$sys {
+            #[inline]
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => sys::$sys::$sys_variant),+
+                }
+            }
+        }
+
+        impl TryFrom<sys::$sys> for $name {
+            type Error = Error;
+
+            #[inline]
+            fn try_from(value: sys::$sys) -> Result<Self> {
+                return Self::try_from(value as u32);
+            }
+        }
+
+        /// Converts a raw value, as returned by the C API, which might be unknown to the bundled headers.
+        impl TryFrom<u32> for $name {
+            type Error = Error;
+
+            fn try_from(value: u32) -> Result<Self> {
+                return match value {
+                    $(value if value == sys::$sys::$sys_variant as u32 => Ok(Self::$variant),)+
+                    other => Err(Error {
+                        code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+                        err_msg: Some(format!(concat!("Unsupported ", stringify!($name), " `{}`."), other)),
+                    }),
+                };
+            }
+        }
+    };
+}
+
+sys_enum! {
+    /// Target language of a compiler.
+    pub enum Backend: spvc_backend {
+        None = SPVC_BACKEND_NONE,
+        Glsl = SPVC_BACKEND_GLSL,
+        Hlsl = SPVC_BACKEND_HLSL,
+        Msl = SPVC_BACKEND_MSL,
+        Cpp = SPVC_BACKEND_CPP,
+        Json = SPVC_BACKEND_JSON,
+    }
+}
+
+sys_enum! {
+    /// Stage of the pipeline an entry point runs at.
+    pub enum ExecutionModel: SpvExecutionModel {
+        Vertex = SpvExecutionModelVertex,
+        TessellationControl = SpvExecutionModelTessellationControl,
+        TessellationEvaluation = SpvExecutionModelTessellationEvaluation,
+        Geometry = SpvExecutionModelGeometry,
+        Fragment = SpvExecutionModelFragment,
+        GLCompute = SpvExecutionModelGLCompute,
+        Kernel = SpvExecutionModelKernel,
+        TaskNV = SpvExecutionModelTaskNV,
+        MeshNV = SpvExecutionModelMeshNV,
+        RayGenerationKHR = SpvExecutionModelRayGenerationKHR,
+        IntersectionKHR = SpvExecutionModelIntersectionKHR,
+        AnyHitKHR = SpvExecutionModelAnyHitKHR,
+        ClosestHitKHR = SpvExecutionModelClosestHitKHR,
+        MissKHR = SpvExecutionModelMissKHR,
+        CallableKHR = SpvExecutionModelCallableKHR,
+    }
+}
+
+sys_enum! {
+    /// Mode an entry point executes in, like its workgroup size or its primitive topology.
+    pub enum ExecutionMode: SpvExecutionMode {
+        Invocations = SpvExecutionModeInvocations,
+        SpacingEqual = SpvExecutionModeSpacingEqual,
+        SpacingFractionalEven = SpvExecutionModeSpacingFractionalEven,
+        SpacingFractionalOdd = SpvExecutionModeSpacingFractionalOdd,
+        VertexOrderCw = SpvExecutionModeVertexOrderCw,
+        VertexOrderCcw = SpvExecutionModeVertexOrderCcw,
+        PixelCenterInteger = SpvExecutionModePixelCenterInteger,
+        OriginUpperLeft = SpvExecutionModeOriginUpperLeft,
+        OriginLowerLeft = SpvExecutionModeOriginLowerLeft,
+        EarlyFragmentTests = SpvExecutionModeEarlyFragmentTests,
+        PointMode = SpvExecutionModePointMode,
+        Xfb = SpvExecutionModeXfb,
+        DepthReplacing = SpvExecutionModeDepthReplacing,
+        DepthGreater = SpvExecutionModeDepthGreater,
+        DepthLess = SpvExecutionModeDepthLess,
+        DepthUnchanged = SpvExecutionModeDepthUnchanged,
+        LocalSize = SpvExecutionModeLocalSize,
+        LocalSizeHint = SpvExecutionModeLocalSizeHint,
+        InputPoints = SpvExecutionModeInputPoints,
+        InputLines = SpvExecutionModeInputLines,
+        InputLinesAdjacency = SpvExecutionModeInputLinesAdjacency,
+        Triangles = SpvExecutionModeTriangles,
+        InputTrianglesAdjacency = SpvExecutionModeInputTrianglesAdjacency,
+        Quads = SpvExecutionModeQuads,
+        Isolines = SpvExecutionModeIsolines,
+        OutputVertices = SpvExecutionModeOutputVertices,
+        OutputPoints = SpvExecutionModeOutputPoints,
+        OutputLineStrip = SpvExecutionModeOutputLineStrip,
+        OutputTriangleStrip = SpvExecutionModeOutputTriangleStrip,
+        VecTypeHint = SpvExecutionModeVecTypeHint,
+        ContractionOff = SpvExecutionModeContractionOff,
+        Initializer = SpvExecutionModeInitializer,
+        Finalizer = SpvExecutionModeFinalizer,
+        SubgroupSize = SpvExecutionModeSubgroupSize,
+        SubgroupsPerWorkgroup = SpvExecutionModeSubgroupsPerWorkgroup,
+        SubgroupsPerWorkgroupId = SpvExecutionModeSubgroupsPerWorkgroupId,
+        LocalSizeId = SpvExecutionModeLocalSizeId,
+        LocalSizeHintId = SpvExecutionModeLocalSizeHintId,
+        PostDepthCoverage = SpvExecutionModePostDepthCoverage,
+        DenormPreserve = SpvExecutionModeDenormPreserve,
+        DenormFlushToZero = SpvExecutionModeDenormFlushToZero,
+        SignedZeroInfNanPreserve = SpvExecutionModeSignedZeroInfNanPreserve,
+        RoundingModeRTE = SpvExecutionModeRoundingModeRTE,
+        RoundingModeRTZ = SpvExecutionModeRoundingModeRTZ,
+        StencilRefReplacingEXT = SpvExecutionModeStencilRefReplacingEXT,
+        OutputLinesNV = SpvExecutionModeOutputLinesNV,
+        OutputPrimitivesNV = SpvExecutionModeOutputPrimitivesNV,
+        DerivativeGroupQuadsNV = SpvExecutionModeDerivativeGroupQuadsNV,
+        DerivativeGroupLinearNV = SpvExecutionModeDerivativeGroupLinearNV,
+        OutputTrianglesNV = SpvExecutionModeOutputTrianglesNV,
+        PixelInterlockOrderedEXT = SpvExecutionModePixelInterlockOrderedEXT,
+        PixelInterlockUnorderedEXT = SpvExecutionModePixelInterlockUnorderedEXT,
+        SampleInterlockOrderedEXT = SpvExecutionModeSampleInterlockOrderedEXT,
+        SampleInterlockUnorderedEXT = SpvExecutionModeSampleInterlockUnorderedEXT,
+        ShadingRateInterlockOrderedEXT = SpvExecutionModeShadingRateInterlockOrderedEXT,
+        ShadingRateInterlockUnorderedEXT = SpvExecutionModeShadingRateInterlockUnorderedEXT,
+        MaxWorkgroupSizeINTEL = SpvExecutionModeMaxWorkgroupSizeINTEL,
+        MaxWorkDimINTEL = SpvExecutionModeMaxWorkDimINTEL,
+        NoGlobalOffsetINTEL = SpvExecutionModeNoGlobalOffsetINTEL,
+        NumSIMDWorkitemsINTEL = SpvExecutionModeNumSIMDWorkitemsINTEL,
+    }
+}
+
+sys_enum! {
+    /// Decoration of an id or struct member.
+    pub enum Decoration: SpvDecoration {
+        RelaxedPrecision = SpvDecorationRelaxedPrecision,
+        SpecId = SpvDecorationSpecId,
+        Block = SpvDecorationBlock,
+        BufferBlock = SpvDecorationBufferBlock,
+        RowMajor = SpvDecorationRowMajor,
+        ColMajor = SpvDecorationColMajor,
+        ArrayStride = SpvDecorationArrayStride,
+        MatrixStride = SpvDecorationMatrixStride,
+        GLSLShared = SpvDecorationGLSLShared,
+        GLSLPacked = SpvDecorationGLSLPacked,
+        CPacked = SpvDecorationCPacked,
+        BuiltIn = SpvDecorationBuiltIn,
+        NoPerspective = SpvDecorationNoPerspective,
+        Flat = SpvDecorationFlat,
+        Patch = SpvDecorationPatch,
+        Centroid = SpvDecorationCentroid,
+        Sample = SpvDecorationSample,
+        Invariant = SpvDecorationInvariant,
+        Restrict = SpvDecorationRestrict,
+        Aliased = SpvDecorationAliased,
+        Volatile = SpvDecorationVolatile,
+        Constant = SpvDecorationConstant,
+        Coherent = SpvDecorationCoherent,
+        NonWritable = SpvDecorationNonWritable,
+        NonReadable = SpvDecorationNonReadable,
+        Uniform = SpvDecorationUniform,
+        UniformId = SpvDecorationUniformId,
+        SaturatedConversion = SpvDecorationSaturatedConversion,
+        Stream = SpvDecorationStream,
+        Location = SpvDecorationLocation,
+        Component = SpvDecorationComponent,
+        Index = SpvDecorationIndex,
+        Binding = SpvDecorationBinding,
+        DescriptorSet = SpvDecorationDescriptorSet,
+        Offset = SpvDecorationOffset,
+        XfbBuffer = SpvDecorationXfbBuffer,
+        XfbStride = SpvDecorationXfbStride,
+        FuncParamAttr = SpvDecorationFuncParamAttr,
+        FPRoundingMode = SpvDecorationFPRoundingMode,
+        FPFastMathMode = SpvDecorationFPFastMathMode,
+        LinkageAttributes = SpvDecorationLinkageAttributes,
+        NoContraction = SpvDecorationNoContraction,
+        InputAttachmentIndex = SpvDecorationInputAttachmentIndex,
+        Alignment = SpvDecorationAlignment,
+        MaxByteOffset = SpvDecorationMaxByteOffset,
+        AlignmentId = SpvDecorationAlignmentId,
+        MaxByteOffsetId = SpvDecorationMaxByteOffsetId,
+        NoSignedWrap = SpvDecorationNoSignedWrap,
+        NoUnsignedWrap = SpvDecorationNoUnsignedWrap,
+        ExplicitInterpAMD = SpvDecorationExplicitInterpAMD,
+        OverrideCoverageNV = SpvDecorationOverrideCoverageNV,
+        PassthroughNV = SpvDecorationPassthroughNV,
+        ViewportRelativeNV = SpvDecorationViewportRelativeNV,
+        SecondaryViewportRelativeNV = SpvDecorationSecondaryViewportRelativeNV,
+        PerPrimitiveNV = SpvDecorationPerPrimitiveNV,
+        PerViewNV = SpvDecorationPerViewNV,
+        PerTaskNV = SpvDecorationPerTaskNV,
+        PerVertexNV = SpvDecorationPerVertexNV,
+        NonUniform = SpvDecorationNonUniform,
+        RestrictPointer = SpvDecorationRestrictPointer,
+        AliasedPointer = SpvDecorationAliasedPointer,
+        ReferencedIndirectlyINTEL = SpvDecorationReferencedIndirectlyINTEL,
+        CounterBuffer = SpvDecorationCounterBuffer,
+        UserSemantic = SpvDecorationHlslSemanticGOOGLE,
+        UserTypeGOOGLE = SpvDecorationUserTypeGOOGLE,
+        RegisterINTEL = SpvDecorationRegisterINTEL,
+        MemoryINTEL = SpvDecorationMemoryINTEL,
+        NumbanksINTEL = SpvDecorationNumbanksINTEL,
+        BankwidthINTEL = SpvDecorationBankwidthINTEL,
+        MaxPrivateCopiesINTEL = SpvDecorationMaxPrivateCopiesINTEL,
+        SinglepumpINTEL = SpvDecorationSinglepumpINTEL,
+        DoublepumpINTEL = SpvDecorationDoublepumpINTEL,
+        MaxReplicatesINTEL = SpvDecorationMaxReplicatesINTEL,
+        SimpleDualPortINTEL = SpvDecorationSimpleDualPortINTEL,
+        MergeINTEL = SpvDecorationMergeINTEL,
+        BankBitsINTEL = SpvDecorationBankBitsINTEL,
+        ForcePow2DepthINTEL = SpvDecorationForcePow2DepthINTEL,
+    }
+}
+
+sys_enum! {
+    /// Builtin variable of the SPIR-V specification, like `gl_Position`.
+    pub enum BuiltIn: SpvBuiltIn {
+        Position = SpvBuiltInPosition,
+        PointSize = SpvBuiltInPointSize,
+        ClipDistance = SpvBuiltInClipDistance,
+        CullDistance = SpvBuiltInCullDistance,
+        VertexId = SpvBuiltInVertexId,
+        InstanceId = SpvBuiltInInstanceId,
+        PrimitiveId = SpvBuiltInPrimitiveId,
+        InvocationId = SpvBuiltInInvocationId,
+        Layer = SpvBuiltInLayer,
+        ViewportIndex = SpvBuiltInViewportIndex,
+        TessLevelOuter = SpvBuiltInTessLevelOuter,
+        TessLevelInner = SpvBuiltInTessLevelInner,
+        TessCoord = SpvBuiltInTessCoord,
+        PatchVertices = SpvBuiltInPatchVertices,
+        FragCoord = SpvBuiltInFragCoord,
+        PointCoord = SpvBuiltInPointCoord,
+        FrontFacing = SpvBuiltInFrontFacing,
+        SampleId = SpvBuiltInSampleId,
+        SamplePosition = SpvBuiltInSamplePosition,
+        SampleMask = SpvBuiltInSampleMask,
+        FragDepth = SpvBuiltInFragDepth,
+        HelperInvocation = SpvBuiltInHelperInvocation,
+        NumWorkgroups = SpvBuiltInNumWorkgroups,
+        WorkgroupSize = SpvBuiltInWorkgroupSize,
+        WorkgroupId = SpvBuiltInWorkgroupId,
+        LocalInvocationId = SpvBuiltInLocalInvocationId,
+        GlobalInvocationId = SpvBuiltInGlobalInvocationId,
+        LocalInvocationIndex = SpvBuiltInLocalInvocationIndex,
+        WorkDim = SpvBuiltInWorkDim,
+        GlobalSize = SpvBuiltInGlobalSize,
+        EnqueuedWorkgroupSize = SpvBuiltInEnqueuedWorkgroupSize,
+        GlobalOffset = SpvBuiltInGlobalOffset,
+        GlobalLinearId = SpvBuiltInGlobalLinearId,
+        SubgroupSize = SpvBuiltInSubgroupSize,
+        SubgroupMaxSize = SpvBuiltInSubgroupMaxSize,
+        NumSubgroups = SpvBuiltInNumSubgroups,
+        NumEnqueuedSubgroups = SpvBuiltInNumEnqueuedSubgroups,
+        SubgroupId = SpvBuiltInSubgroupId,
+        SubgroupLocalInvocationId = SpvBuiltInSubgroupLocalInvocationId,
+        VertexIndex = SpvBuiltInVertexIndex,
+        InstanceIndex = SpvBuiltInInstanceIndex,
+        SubgroupEqMask = SpvBuiltInSubgroupEqMask,
+        SubgroupGeMask = SpvBuiltInSubgroupGeMask,
+        SubgroupGtMask = SpvBuiltInSubgroupGtMask,
+        SubgroupLeMask = SpvBuiltInSubgroupLeMask,
+        SubgroupLtMask = SpvBuiltInSubgroupLtMask,
+        BaseVertex = SpvBuiltInBaseVertex,
+        BaseInstance = SpvBuiltInBaseInstance,
+        DrawIndex = SpvBuiltInDrawIndex,
+        PrimitiveShadingRateKHR = SpvBuiltInPrimitiveShadingRateKHR,
+        DeviceIndex = SpvBuiltInDeviceIndex,
+        ViewIndex = SpvBuiltInViewIndex,
+        ShadingRateKHR = SpvBuiltInShadingRateKHR,
+        BaryCoordNoPerspAMD = SpvBuiltInBaryCoordNoPerspAMD,
+        BaryCoordNoPerspCentroidAMD = SpvBuiltInBaryCoordNoPerspCentroidAMD,
+        BaryCoordNoPerspSampleAMD = SpvBuiltInBaryCoordNoPerspSampleAMD,
+        BaryCoordSmoothAMD = SpvBuiltInBaryCoordSmoothAMD,
+        BaryCoordSmoothCentroidAMD = SpvBuiltInBaryCoordSmoothCentroidAMD,
+        BaryCoordSmoothSampleAMD = SpvBuiltInBaryCoordSmoothSampleAMD,
+        BaryCoordPullModelAMD = SpvBuiltInBaryCoordPullModelAMD,
+        FragStencilRefEXT = SpvBuiltInFragStencilRefEXT,
+        ViewportMaskNV = SpvBuiltInViewportMaskNV,
+        SecondaryPositionNV = SpvBuiltInSecondaryPositionNV,
+        SecondaryViewportMaskNV = SpvBuiltInSecondaryViewportMaskNV,
+        PositionPerViewNV = SpvBuiltInPositionPerViewNV,
+        ViewportMaskPerViewNV = SpvBuiltInViewportMaskPerViewNV,
+        FullyCoveredEXT = SpvBuiltInFullyCoveredEXT,
+        TaskCountNV = SpvBuiltInTaskCountNV,
+        PrimitiveCountNV = SpvBuiltInPrimitiveCountNV,
+        PrimitiveIndicesNV = SpvBuiltInPrimitiveIndicesNV,
+        ClipDistancePerViewNV = SpvBuiltInClipDistancePerViewNV,
+        CullDistancePerViewNV = SpvBuiltInCullDistancePerViewNV,
+        LayerPerViewNV = SpvBuiltInLayerPerViewNV,
+        MeshViewCountNV = SpvBuiltInMeshViewCountNV,
+        MeshViewIndicesNV = SpvBuiltInMeshViewIndicesNV,
+        BaryCoordNV = SpvBuiltInBaryCoordNV,
+        BaryCoordNoPerspNV = SpvBuiltInBaryCoordNoPerspNV,
+        FragSizeEXT = SpvBuiltInFragSizeEXT,
+        FragInvocationCountEXT = SpvBuiltInFragInvocationCountEXT,
+        LaunchIdKHR = SpvBuiltInLaunchIdKHR,
+        LaunchSizeKHR = SpvBuiltInLaunchSizeKHR,
+        WorldRayOriginKHR = SpvBuiltInWorldRayOriginKHR,
+        WorldRayDirectionKHR = SpvBuiltInWorldRayDirectionKHR,
+        ObjectRayOriginKHR = SpvBuiltInObjectRayOriginKHR,
+        ObjectRayDirectionKHR = SpvBuiltInObjectRayDirectionKHR,
+        RayTminKHR = SpvBuiltInRayTminKHR,
+        RayTmaxKHR = SpvBuiltInRayTmaxKHR,
+        InstanceCustomIndexKHR = SpvBuiltInInstanceCustomIndexKHR,
+        ObjectToWorldKHR = SpvBuiltInObjectToWorldKHR,
+        WorldToObjectKHR = SpvBuiltInWorldToObjectKHR,
+        HitTNV = SpvBuiltInHitTNV,
+        HitKindKHR = SpvBuiltInHitKindKHR,
+        IncomingRayFlagsKHR = SpvBuiltInIncomingRayFlagsKHR,
+        RayGeometryIndexKHR = SpvBuiltInRayGeometryIndexKHR,
+        WarpsPerSMNV = SpvBuiltInWarpsPerSMNV,
+        SMCountNV = SpvBuiltInSMCountNV,
+        WarpIDNV = SpvBuiltInWarpIDNV,
+        SMIDNV = SpvBuiltInSMIDNV,
+    }
+}
+
+sys_enum! {
+    /// Fundamental type of a SPIR-V type, ignoring vector, matrix and array dimensions.
+    pub enum BaseType: spvc_basetype {
+        Unknown = SPVC_BASETYPE_UNKNOWN,
+        Void = SPVC_BASETYPE_VOID,
+        Boolean = SPVC_BASETYPE_BOOLEAN,
+        Int8 = SPVC_BASETYPE_INT8,
+        UInt8 = SPVC_BASETYPE_UINT8,
+        Int16 = SPVC_BASETYPE_INT16,
+        UInt16 = SPVC_BASETYPE_UINT16,
+        Int32 = SPVC_BASETYPE_INT32,
+        UInt32 = SPVC_BASETYPE_UINT32,
+        Int64 = SPVC_BASETYPE_INT64,
+        UInt64 = SPVC_BASETYPE_UINT64,
+        AtomicCounter = SPVC_BASETYPE_ATOMIC_COUNTER,
+        Fp16 = SPVC_BASETYPE_FP16,
+        Fp32 = SPVC_BASETYPE_FP32,
+        Fp64 = SPVC_BASETYPE_FP64,
+        Struct = SPVC_BASETYPE_STRUCT,
+        Image = SPVC_BASETYPE_IMAGE,
+        SampledImage = SPVC_BASETYPE_SAMPLED_IMAGE,
+        Sampler = SPVC_BASETYPE_SAMPLER,
+        AccelerationStructure = SPVC_BASETYPE_ACCELERATION_STRUCTURE,
+    }
+}
+
+sys_enum! {
+    /// Dimensionality of an image.
+    pub enum Dim: SpvDim {
+        Dim1D = SpvDim1D,
+        Dim2D = SpvDim2D,
+        Dim3D = SpvDim3D,
+        Cube = SpvDimCube,
+        Rect = SpvDimRect,
+        Buffer = SpvDimBuffer,
+        SubpassData = SpvDimSubpassData,
+    }
+}
+
+sys_enum! {
+    /// Texel format of a storage image.
+    pub enum ImageFormat: SpvImageFormat {
+        Unknown = SpvImageFormatUnknown,
+        Rgba32f = SpvImageFormatRgba32f,
+        Rgba16f = SpvImageFormatRgba16f,
+        R32f = SpvImageFormatR32f,
+        Rgba8 = SpvImageFormatRgba8,
+        Rgba8Snorm = SpvImageFormatRgba8Snorm,
+        Rg32f = SpvImageFormatRg32f,
+        Rg16f = SpvImageFormatRg16f,
+        R11fG11fB10f = SpvImageFormatR11fG11fB10f,
+        R16f = SpvImageFormatR16f,
+        Rgba16 = SpvImageFormatRgba16,
+        Rgb10A2 = SpvImageFormatRgb10A2,
+        Rg16 = SpvImageFormatRg16,
+        Rg8 = SpvImageFormatRg8,
+        R16 = SpvImageFormatR16,
+        R8 = SpvImageFormatR8,
+        Rgba16Snorm = SpvImageFormatRgba16Snorm,
+        Rg16Snorm = SpvImageFormatRg16Snorm,
+        Rg8Snorm = SpvImageFormatRg8Snorm,
+        R16Snorm = SpvImageFormatR16Snorm,
+        R8Snorm = SpvImageFormatR8Snorm,
+        Rgba32i = SpvImageFormatRgba32i,
+        Rgba16i = SpvImageFormatRgba16i,
+        Rgba8i = SpvImageFormatRgba8i,
+        R32i = SpvImageFormatR32i,
+        Rg32i = SpvImageFormatRg32i,
+        Rg16i = SpvImageFormatRg16i,
+        Rg8i = SpvImageFormatRg8i,
+        R16i = SpvImageFormatR16i,
+        R8i = SpvImageFormatR8i,
+        Rgba32ui = SpvImageFormatRgba32ui,
+        Rgba16ui = SpvImageFormatRgba16ui,
+        Rgba8ui = SpvImageFormatRgba8ui,
+        R32ui = SpvImageFormatR32ui,
+        Rgb10a2ui = SpvImageFormatRgb10a2ui,
+        Rg32ui = SpvImageFormatRg32ui,
+        Rg16ui = SpvImageFormatRg16ui,
+        Rg8ui = SpvImageFormatRg8ui,
+        R16ui = SpvImageFormatR16ui,
+        R8ui = SpvImageFormatR8ui,
+        R64ui = SpvImageFormatR64ui,
+        R64i = SpvImageFormatR64i,
+    }
+}
+
+sys_enum! {
+    /// Access allowed to a storage image.
+    pub enum AccessQualifier: SpvAccessQualifier {
+        ReadOnly = SpvAccessQualifierReadOnly,
+        WriteOnly = SpvAccessQualifierWriteOnly,
+        ReadWrite = SpvAccessQualifierReadWrite,
+    }
+}
+
+sys_enum! {
+    /// Storage class of a variable or pointer.
+    pub enum StorageClass: SpvStorageClass {
+        UniformConstant = SpvStorageClassUniformConstant,
+        Input = SpvStorageClassInput,
+        Uniform = SpvStorageClassUniform,
+        Output = SpvStorageClassOutput,
+        Workgroup = SpvStorageClassWorkgroup,
+        CrossWorkgroup = SpvStorageClassCrossWorkgroup,
+        Private = SpvStorageClassPrivate,
+        Function = SpvStorageClassFunction,
+        Generic = SpvStorageClassGeneric,
+        PushConstant = SpvStorageClassPushConstant,
+        AtomicCounter = SpvStorageClassAtomicCounter,
+        Image = SpvStorageClassImage,
+        StorageBuffer = SpvStorageClassStorageBuffer,
+        CallableDataKHR = SpvStorageClassCallableDataKHR,
+        IncomingCallableDataKHR = SpvStorageClassIncomingCallableDataKHR,
+        RayPayloadKHR = SpvStorageClassRayPayloadKHR,
+        HitAttributeKHR = SpvStorageClassHitAttributeKHR,
+        IncomingRayPayloadKHR = SpvStorageClassIncomingRayPayloadKHR,
+        ShaderRecordBufferKHR = SpvStorageClassShaderRecordBufferKHR,
+        PhysicalStorageBuffer = SpvStorageClassPhysicalStorageBuffer,
+        CodeSectionINTEL = SpvStorageClassCodeSectionINTEL,
+    }
+}
+
+sys_enum! {
+    /// Apple platform targeted by the Metal compiler.
+    pub enum MslPlatform: spvc_msl_platform {
+        Ios = SPVC_MSL_PLATFORM_IOS,
+        MacOs = SPVC_MSL_PLATFORM_MACOS,
+    }
+}
+
+sys_enum! {
+    /// Kind of a reflected shader resource.
+    pub enum ResourceType: spvc_resource_type {
+        Unknown = SPVC_RESOURCE_TYPE_UNKNOWN,
+        UniformBuffer = SPVC_RESOURCE_TYPE_UNIFORM_BUFFER,
+        StorageBuffer = SPVC_RESOURCE_TYPE_STORAGE_BUFFER,
+        StageInput = SPVC_RESOURCE_TYPE_STAGE_INPUT,
+        StageOutput = SPVC_RESOURCE_TYPE_STAGE_OUTPUT,
+        SubpassInput = SPVC_RESOURCE_TYPE_SUBPASS_INPUT,
+        StorageImage = SPVC_RESOURCE_TYPE_STORAGE_IMAGE,
+        SampledImage = SPVC_RESOURCE_TYPE_SAMPLED_IMAGE,
+        AtomicCounter = SPVC_RESOURCE_TYPE_ATOMIC_COUNTER,
+        PushConstant = SPVC_RESOURCE_TYPE_PUSH_CONSTANT,
+        SeparateImage = SPVC_RESOURCE_TYPE_SEPARATE_IMAGE,
+        SeparateSamplers = SPVC_RESOURCE_TYPE_SEPARATE_SAMPLERS,
+        AccelerationStructure = SPVC_RESOURCE_TYPE_ACCELERATION_STRUCTURE,
+        RayQuery = SPVC_RESOURCE_TYPE_RAY_QUERY,
+        ShaderRecordBuffer = SPVC_RESOURCE_TYPE_SHADER_RECORD_BUFFER,
+        GlPlainUniform = SPVC_RESOURCE_TYPE_GL_PLAIN_UNIFORM,
+    }
+}
+
+sys_enum! {
+    /// Kind of a reflected builtin resource.
+    pub enum BuiltinResourceType: spvc_builtin_resource_type {
+        Unknown = SPVC_BUILTIN_RESOURCE_TYPE_UNKNOWN,
+        StageInput = SPVC_BUILTIN_RESOURCE_TYPE_STAGE_INPUT,
+        StageOutput = SPVC_BUILTIN_RESOURCE_TYPE_STAGE_OUTPUT,
+    }
+}
+
+sys_enum! {
+    /// Option of a compiler, common to every backend or specific to one of them.
+    pub enum CompilerOption: spvc_compiler_option {
+        ForceTemporary = SPVC_COMPILER_OPTION_FORCE_TEMPORARY,
+        FlattenMultidimensionalArrays = SPVC_COMPILER_OPTION_FLATTEN_MULTIDIMENSIONAL_ARRAYS,
+        FixupDepthConvention = SPVC_COMPILER_OPTION_FIXUP_DEPTH_CONVENTION,
+        FlipVertexY = SPVC_COMPILER_OPTION_FLIP_VERTEX_Y,
+        GlslSupportNonzeroBaseInstance = SPVC_COMPILER_OPTION_GLSL_SUPPORT_NONZERO_BASE_INSTANCE,
+        GlslSeparateShaderObjects = SPVC_COMPILER_OPTION_GLSL_SEPARATE_SHADER_OBJECTS,
+        GlslEnable420packExtension = SPVC_COMPILER_OPTION_GLSL_ENABLE_420PACK_EXTENSION,
+        GlslVersion = SPVC_COMPILER_OPTION_GLSL_VERSION,
+        GlslEs = SPVC_COMPILER_OPTION_GLSL_ES,
+        GlslVulkanSemantics = SPVC_COMPILER_OPTION_GLSL_VULKAN_SEMANTICS,
+        GlslEsDefaultFloatPrecisionHighp = SPVC_COMPILER_OPTION_GLSL_ES_DEFAULT_FLOAT_PRECISION_HIGHP,
+        GlslEsDefaultIntPrecisionHighp = SPVC_COMPILER_OPTION_GLSL_ES_DEFAULT_INT_PRECISION_HIGHP,
+        HlslShaderModel = SPVC_COMPILER_OPTION_HLSL_SHADER_MODEL,
+        HlslPointSizeCompat = SPVC_COMPILER_OPTION_HLSL_POINT_SIZE_COMPAT,
+        HlslPointCoordCompat = SPVC_COMPILER_OPTION_HLSL_POINT_COORD_COMPAT,
+        HlslSupportNonzeroBaseVertexBaseInstance = SPVC_COMPILER_OPTION_HLSL_SUPPORT_NONZERO_BASE_VERTEX_BASE_INSTANCE,
+        MslVersion = SPVC_COMPILER_OPTION_MSL_VERSION,
+        MslTexelBufferTextureWidth = SPVC_COMPILER_OPTION_MSL_TEXEL_BUFFER_TEXTURE_WIDTH,
+        MslAuxBufferIndex = SPVC_COMPILER_OPTION_MSL_AUX_BUFFER_INDEX,
+        MslIndirectParamsBufferIndex = SPVC_COMPILER_OPTION_MSL_INDIRECT_PARAMS_BUFFER_INDEX,
+        MslShaderOutputBufferIndex = SPVC_COMPILER_OPTION_MSL_SHADER_OUTPUT_BUFFER_INDEX,
+        MslShaderPatchOutputBufferIndex = SPVC_COMPILER_OPTION_MSL_SHADER_PATCH_OUTPUT_BUFFER_INDEX,
+        MslShaderTessFactorOutputBufferIndex = SPVC_COMPILER_OPTION_MSL_SHADER_TESS_FACTOR_OUTPUT_BUFFER_INDEX,
+        MslShaderInputWorkgroupIndex = SPVC_COMPILER_OPTION_MSL_SHADER_INPUT_WORKGROUP_INDEX,
+        MslEnablePointSizeBuiltin = SPVC_COMPILER_OPTION_MSL_ENABLE_POINT_SIZE_BUILTIN,
+        MslDisableRasterization = SPVC_COMPILER_OPTION_MSL_DISABLE_RASTERIZATION,
+        MslCaptureOutputToBuffer = SPVC_COMPILER_OPTION_MSL_CAPTURE_OUTPUT_TO_BUFFER,
+        MslSwizzleTextureSamples = SPVC_COMPILER_OPTION_MSL_SWIZZLE_TEXTURE_SAMPLES,
+        MslPadFragmentOutputComponents = SPVC_COMPILER_OPTION_MSL_PAD_FRAGMENT_OUTPUT_COMPONENTS,
+        MslTessDomainOriginLowerLeft = SPVC_COMPILER_OPTION_MSL_TESS_DOMAIN_ORIGIN_LOWER_LEFT,
+        MslPlatform = SPVC_COMPILER_OPTION_MSL_PLATFORM,
+        MslArgumentBuffers = SPVC_COMPILER_OPTION_MSL_ARGUMENT_BUFFERS,
+        GlslEmitPushConstantAsUniformBuffer = SPVC_COMPILER_OPTION_GLSL_EMIT_PUSH_CONSTANT_AS_UNIFORM_BUFFER,
+        MslTextureBufferNative = SPVC_COMPILER_OPTION_MSL_TEXTURE_BUFFER_NATIVE,
+        GlslEmitUniformBufferAsPlainUniforms = SPVC_COMPILER_OPTION_GLSL_EMIT_UNIFORM_BUFFER_AS_PLAIN_UNIFORMS,
+        MslBufferSizeBufferIndex = SPVC_COMPILER_OPTION_MSL_BUFFER_SIZE_BUFFER_INDEX,
+        EmitLineDirectives = SPVC_COMPILER_OPTION_EMIT_LINE_DIRECTIVES,
+        MslMultiview = SPVC_COMPILER_OPTION_MSL_MULTIVIEW,
+        MslViewMaskBufferIndex = SPVC_COMPILER_OPTION_MSL_VIEW_MASK_BUFFER_INDEX,
+        MslDeviceIndex = SPVC_COMPILER_OPTION_MSL_DEVICE_INDEX,
+        MslViewIndexFromDeviceIndex = SPVC_COMPILER_OPTION_MSL_VIEW_INDEX_FROM_DEVICE_INDEX,
+        MslDispatchBase = SPVC_COMPILER_OPTION_MSL_DISPATCH_BASE,
+        MslDynamicOffsetsBufferIndex = SPVC_COMPILER_OPTION_MSL_DYNAMIC_OFFSETS_BUFFER_INDEX,
+        MslTexture1dAs2d = SPVC_COMPILER_OPTION_MSL_TEXTURE_1D_AS_2D,
+        MslEnableBaseIndexZero = SPVC_COMPILER_OPTION_MSL_ENABLE_BASE_INDEX_ZERO,
+        MslIosFramebufferFetchSubpass = SPVC_COMPILER_OPTION_MSL_IOS_FRAMEBUFFER_FETCH_SUBPASS,
+        MslInvariantFpMath = SPVC_COMPILER_OPTION_MSL_INVARIANT_FP_MATH,
+        MslEmulateCubemapArray = SPVC_COMPILER_OPTION_MSL_EMULATE_CUBEMAP_ARRAY,
+        MslEnableDecorationBinding = SPVC_COMPILER_OPTION_MSL_ENABLE_DECORATION_BINDING,
+        MslForceActiveArgumentBufferResources = SPVC_COMPILER_OPTION_MSL_FORCE_ACTIVE_ARGUMENT_BUFFER_RESOURCES,
+        MslForceNativeArrays = SPVC_COMPILER_OPTION_MSL_FORCE_NATIVE_ARRAYS,
+        EnableStorageImageQualifierDeduction = SPVC_COMPILER_OPTION_ENABLE_STORAGE_IMAGE_QUALIFIER_DEDUCTION,
+        HlslForceStorageBufferAsUav = SPVC_COMPILER_OPTION_HLSL_FORCE_STORAGE_BUFFER_AS_UAV,
+        ForceZeroInitializedVariables = SPVC_COMPILER_OPTION_FORCE_ZERO_INITIALIZED_VARIABLES,
+        HlslNonwritableUavTextureAsSrv = SPVC_COMPILER_OPTION_HLSL_NONWRITABLE_UAV_TEXTURE_AS_SRV,
+        MslEnableFragOutputMask = SPVC_COMPILER_OPTION_MSL_ENABLE_FRAG_OUTPUT_MASK,
+        MslEnableFragDepthBuiltin = SPVC_COMPILER_OPTION_MSL_ENABLE_FRAG_DEPTH_BUILTIN,
+        MslEnableFragStencilRefBuiltin = SPVC_COMPILER_OPTION_MSL_ENABLE_FRAG_STENCIL_REF_BUILTIN,
+        MslEnableClipDistanceUserVarying = SPVC_COMPILER_OPTION_MSL_ENABLE_CLIP_DISTANCE_USER_VARYING,
+        HlslEnable16bitTypes = SPVC_COMPILER_OPTION_HLSL_ENABLE_16BIT_TYPES,
+        MslMultiPatchWorkgroup = SPVC_COMPILER_OPTION_MSL_MULTI_PATCH_WORKGROUP,
+        MslShaderInputBufferIndex = SPVC_COMPILER_OPTION_MSL_SHADER_INPUT_BUFFER_INDEX,
+        MslShaderIndexBufferIndex = SPVC_COMPILER_OPTION_MSL_SHADER_INDEX_BUFFER_INDEX,
+        MslVertexForTessellation = SPVC_COMPILER_OPTION_MSL_VERTEX_FOR_TESSELLATION,
+        MslVertexIndexType = SPVC_COMPILER_OPTION_MSL_VERTEX_INDEX_TYPE,
+        GlslForceFlattenedIoBlocks = SPVC_COMPILER_OPTION_GLSL_FORCE_FLATTENED_IO_BLOCKS,
+        MslMultiviewLayeredRendering = SPVC_COMPILER_OPTION_MSL_MULTIVIEW_LAYERED_RENDERING,
+        MslArrayedSubpassInput = SPVC_COMPILER_OPTION_MSL_ARRAYED_SUBPASS_INPUT,
+        MslR32uiLinearTextureAlignment = SPVC_COMPILER_OPTION_MSL_R32UI_LINEAR_TEXTURE_ALIGNMENT,
+        MslR32uiAlignmentConstantId = SPVC_COMPILER_OPTION_MSL_R32UI_ALIGNMENT_CONSTANT_ID,
+        HlslFlattenMatrixVertexInputSemantics = SPVC_COMPILER_OPTION_HLSL_FLATTEN_MATRIX_VERTEX_INPUT_SEMANTICS,
+        MslIosUseSimdgroupFunctions = SPVC_COMPILER_OPTION_MSL_IOS_USE_SIMDGROUP_FUNCTIONS,
+        MslEmulateSubgroups = SPVC_COMPILER_OPTION_MSL_EMULATE_SUBGROUPS,
+        MslFixedSubgroupSize = SPVC_COMPILER_OPTION_MSL_FIXED_SUBGROUP_SIZE,
+        MslForceSampleRateShading = SPVC_COMPILER_OPTION_MSL_FORCE_SAMPLE_RATE_SHADING,
+        MslIosSupportBaseVertexInstance = SPVC_COMPILER_OPTION_MSL_IOS_SUPPORT_BASE_VERTEX_INSTANCE,
+        GlslOvrMultiviewViewCount = SPVC_COMPILER_OPTION_GLSL_OVR_MULTIVIEW_VIEW_COUNT,
+        RelaxNanChecks = SPVC_COMPILER_OPTION_RELAX_NAN_CHECKS,
+        MslRawBufferTeseInput = SPVC_COMPILER_OPTION_MSL_RAW_BUFFER_TESE_INPUT,
+        MslShaderPatchInputBufferIndex = SPVC_COMPILER_OPTION_MSL_SHADER_PATCH_INPUT_BUFFER_INDEX,
+        MslManualHelperInvocationUpdates = SPVC_COMPILER_OPTION_MSL_MANUAL_HELPER_INVOCATION_UPDATES,
+        MslCheckDiscardedFragStores = SPVC_COMPILER_OPTION_MSL_CHECK_DISCARDED_FRAG_STORES,
+        GlslEnableRowMajorLoadWorkaround = SPVC_COMPILER_OPTION_GLSL_ENABLE_ROW_MAJOR_LOAD_WORKAROUND,
+        MslArgumentBuffersTier = SPVC_COMPILER_OPTION_MSL_ARGUMENT_BUFFERS_TIER,
+        MslSampleDrefLodArrayAsGrad = SPVC_COMPILER_OPTION_MSL_SAMPLE_DREF_LOD_ARRAY_AS_GRAD,
+        MslReadwriteTextureFences = SPVC_COMPILER_OPTION_MSL_READWRITE_TEXTURE_FENCES,
+        MslReplaceRecursiveInputs = SPVC_COMPILER_OPTION_MSL_REPLACE_RECURSIVE_INPUTS,
+        MslAgxManualCubeGradFixup = SPVC_COMPILER_OPTION_MSL_AGX_MANUAL_CUBE_GRAD_FIXUP,
+        MslForceFragmentWithSideEffectsExecution = SPVC_COMPILER_OPTION_MSL_FORCE_FRAGMENT_WITH_SIDE_EFFECTS_EXECUTION,
+    }
+}
+
+sys_enum! {
+    /// Feature of the SPIR-V specification declared by a module.
+    pub enum Capability: SpvCapability {
+        Matrix = SpvCapabilityMatrix,
+        Shader = SpvCapabilityShader,
+        Geometry = SpvCapabilityGeometry,
+        Tessellation = SpvCapabilityTessellation,
+        Addresses = SpvCapabilityAddresses,
+        Linkage = SpvCapabilityLinkage,
+        Kernel = SpvCapabilityKernel,
+        Vector16 = SpvCapabilityVector16,
+        Float16Buffer = SpvCapabilityFloat16Buffer,
+        Float16 = SpvCapabilityFloat16,
+        Float64 = SpvCapabilityFloat64,
+        Int64 = SpvCapabilityInt64,
+        Int64Atomics = SpvCapabilityInt64Atomics,
+        ImageBasic = SpvCapabilityImageBasic,
+        ImageReadWrite = SpvCapabilityImageReadWrite,
+        ImageMipmap = SpvCapabilityImageMipmap,
+        Pipes = SpvCapabilityPipes,
+        Groups = SpvCapabilityGroups,
+        DeviceEnqueue = SpvCapabilityDeviceEnqueue,
+        LiteralSampler = SpvCapabilityLiteralSampler,
+        AtomicStorage = SpvCapabilityAtomicStorage,
+        Int16 = SpvCapabilityInt16,
+        TessellationPointSize = SpvCapabilityTessellationPointSize,
+        GeometryPointSize = SpvCapabilityGeometryPointSize,
+        ImageGatherExtended = SpvCapabilityImageGatherExtended,
+        StorageImageMultisample = SpvCapabilityStorageImageMultisample,
+        UniformBufferArrayDynamicIndexing = SpvCapabilityUniformBufferArrayDynamicIndexing,
+        SampledImageArrayDynamicIndexing = SpvCapabilitySampledImageArrayDynamicIndexing,
+        StorageBufferArrayDynamicIndexing = SpvCapabilityStorageBufferArrayDynamicIndexing,
+        StorageImageArrayDynamicIndexing = SpvCapabilityStorageImageArrayDynamicIndexing,
+        ClipDistance = SpvCapabilityClipDistance,
+        CullDistance = SpvCapabilityCullDistance,
+        ImageCubeArray = SpvCapabilityImageCubeArray,
+        SampleRateShading = SpvCapabilitySampleRateShading,
+        ImageRect = SpvCapabilityImageRect,
+        SampledRect = SpvCapabilitySampledRect,
+        GenericPointer = SpvCapabilityGenericPointer,
+        Int8 = SpvCapabilityInt8,
+        InputAttachment = SpvCapabilityInputAttachment,
+        SparseResidency = SpvCapabilitySparseResidency,
+        MinLod = SpvCapabilityMinLod,
+        Sampled1D = SpvCapabilitySampled1D,
+        Image1D = SpvCapabilityImage1D,
+        SampledCubeArray = SpvCapabilitySampledCubeArray,
+        SampledBuffer = SpvCapabilitySampledBuffer,
+        ImageBuffer = SpvCapabilityImageBuffer,
+        ImageMSArray = SpvCapabilityImageMSArray,
+        StorageImageExtendedFormats = SpvCapabilityStorageImageExtendedFormats,
+        ImageQuery = SpvCapabilityImageQuery,
+        DerivativeControl = SpvCapabilityDerivativeControl,
+        InterpolationFunction = SpvCapabilityInterpolationFunction,
+        TransformFeedback = SpvCapabilityTransformFeedback,
+        GeometryStreams = SpvCapabilityGeometryStreams,
+        StorageImageReadWithoutFormat = SpvCapabilityStorageImageReadWithoutFormat,
+        StorageImageWriteWithoutFormat = SpvCapabilityStorageImageWriteWithoutFormat,
+        MultiViewport = SpvCapabilityMultiViewport,
+        SubgroupDispatch = SpvCapabilitySubgroupDispatch,
+        NamedBarrier = SpvCapabilityNamedBarrier,
+        PipeStorage = SpvCapabilityPipeStorage,
+        GroupNonUniform = SpvCapabilityGroupNonUniform,
+        GroupNonUniformVote = SpvCapabilityGroupNonUniformVote,
+        GroupNonUniformArithmetic = SpvCapabilityGroupNonUniformArithmetic,
+        GroupNonUniformBallot = SpvCapabilityGroupNonUniformBallot,
+        GroupNonUniformShuffle = SpvCapabilityGroupNonUniformShuffle,
+        GroupNonUniformShuffleRelative = SpvCapabilityGroupNonUniformShuffleRelative,
+        GroupNonUniformClustered = SpvCapabilityGroupNonUniformClustered,
+        GroupNonUniformQuad = SpvCapabilityGroupNonUniformQuad,
+        ShaderLayer = SpvCapabilityShaderLayer,
+        ShaderViewportIndex = SpvCapabilityShaderViewportIndex,
+        FragmentShadingRateKHR = SpvCapabilityFragmentShadingRateKHR,
+        SubgroupBallotKHR = SpvCapabilitySubgroupBallotKHR,
+        DrawParameters = SpvCapabilityDrawParameters,
+        SubgroupVoteKHR = SpvCapabilitySubgroupVoteKHR,
+        StorageBuffer16BitAccess = SpvCapabilityStorageBuffer16BitAccess,
+        StorageUniform16 = SpvCapabilityStorageUniform16,
+        StoragePushConstant16 = SpvCapabilityStoragePushConstant16,
+        StorageInputOutput16 = SpvCapabilityStorageInputOutput16,
+        DeviceGroup = SpvCapabilityDeviceGroup,
+        MultiView = SpvCapabilityMultiView,
+        VariablePointersStorageBuffer = SpvCapabilityVariablePointersStorageBuffer,
+        VariablePointers = SpvCapabilityVariablePointers,
+        AtomicStorageOps = SpvCapabilityAtomicStorageOps,
+        SampleMaskPostDepthCoverage = SpvCapabilitySampleMaskPostDepthCoverage,
+        StorageBuffer8BitAccess = SpvCapabilityStorageBuffer8BitAccess,
+        UniformAndStorageBuffer8BitAccess = SpvCapabilityUniformAndStorageBuffer8BitAccess,
+        StoragePushConstant8 = SpvCapabilityStoragePushConstant8,
+        DenormPreserve = SpvCapabilityDenormPreserve,
+        DenormFlushToZero = SpvCapabilityDenormFlushToZero,
+        SignedZeroInfNanPreserve = SpvCapabilitySignedZeroInfNanPreserve,
+        RoundingModeRTE = SpvCapabilityRoundingModeRTE,
+        RoundingModeRTZ = SpvCapabilityRoundingModeRTZ,
+        RayQueryProvisionalKHR = SpvCapabilityRayQueryProvisionalKHR,
+        RayQueryKHR = SpvCapabilityRayQueryKHR,
+        RayTraversalPrimitiveCullingKHR = SpvCapabilityRayTraversalPrimitiveCullingKHR,
+        RayTracingKHR = SpvCapabilityRayTracingKHR,
+        Float16ImageAMD = SpvCapabilityFloat16ImageAMD,
+        ImageGatherBiasLodAMD = SpvCapabilityImageGatherBiasLodAMD,
+        FragmentMaskAMD = SpvCapabilityFragmentMaskAMD,
+        StencilExportEXT = SpvCapabilityStencilExportEXT,
+        ImageReadWriteLodAMD = SpvCapabilityImageReadWriteLodAMD,
+        Int64ImageEXT = SpvCapabilityInt64ImageEXT,
+        ShaderClockKHR = SpvCapabilityShaderClockKHR,
+        SampleMaskOverrideCoverageNV = SpvCapabilitySampleMaskOverrideCoverageNV,
+        GeometryShaderPassthroughNV = SpvCapabilityGeometryShaderPassthroughNV,
+        ShaderViewportIndexLayerEXT = SpvCapabilityShaderViewportIndexLayerEXT,
+        ShaderViewportMaskNV = SpvCapabilityShaderViewportMaskNV,
+        ShaderStereoViewNV = SpvCapabilityShaderStereoViewNV,
+        PerViewAttributesNV = SpvCapabilityPerViewAttributesNV,
+        FragmentFullyCoveredEXT = SpvCapabilityFragmentFullyCoveredEXT,
+        MeshShadingNV = SpvCapabilityMeshShadingNV,
+        ImageFootprintNV = SpvCapabilityImageFootprintNV,
+        FragmentBarycentricNV = SpvCapabilityFragmentBarycentricNV,
+        ComputeDerivativeGroupQuadsNV = SpvCapabilityComputeDerivativeGroupQuadsNV,
+        FragmentDensityEXT = SpvCapabilityFragmentDensityEXT,
+        GroupNonUniformPartitionedNV = SpvCapabilityGroupNonUniformPartitionedNV,
+        ShaderNonUniform = SpvCapabilityShaderNonUniform,
+        RuntimeDescriptorArray = SpvCapabilityRuntimeDescriptorArray,
+        InputAttachmentArrayDynamicIndexing = SpvCapabilityInputAttachmentArrayDynamicIndexing,
+        UniformTexelBufferArrayDynamicIndexing = SpvCapabilityUniformTexelBufferArrayDynamicIndexing,
+        StorageTexelBufferArrayDynamicIndexing = SpvCapabilityStorageTexelBufferArrayDynamicIndexing,
+        UniformBufferArrayNonUniformIndexing = SpvCapabilityUniformBufferArrayNonUniformIndexing,
+        SampledImageArrayNonUniformIndexing = SpvCapabilitySampledImageArrayNonUniformIndexing,
+        StorageBufferArrayNonUniformIndexing = SpvCapabilityStorageBufferArrayNonUniformIndexing,
+        StorageImageArrayNonUniformIndexing = SpvCapabilityStorageImageArrayNonUniformIndexing,
+        InputAttachmentArrayNonUniformIndexing = SpvCapabilityInputAttachmentArrayNonUniformIndexing,
+        UniformTexelBufferArrayNonUniformIndexing = SpvCapabilityUniformTexelBufferArrayNonUniformIndexing,
+        StorageTexelBufferArrayNonUniformIndexing = SpvCapabilityStorageTexelBufferArrayNonUniformIndexing,
+        RayTracingNV = SpvCapabilityRayTracingNV,
+        VulkanMemoryModel = SpvCapabilityVulkanMemoryModel,
+        VulkanMemoryModelDeviceScope = SpvCapabilityVulkanMemoryModelDeviceScope,
+        PhysicalStorageBufferAddresses = SpvCapabilityPhysicalStorageBufferAddresses,
+        ComputeDerivativeGroupLinearNV = SpvCapabilityComputeDerivativeGroupLinearNV,
+        RayTracingProvisionalKHR = SpvCapabilityRayTracingProvisionalKHR,
+        CooperativeMatrixNV = SpvCapabilityCooperativeMatrixNV,
+        FragmentShaderSampleInterlockEXT = SpvCapabilityFragmentShaderSampleInterlockEXT,
+        FragmentShaderShadingRateInterlockEXT = SpvCapabilityFragmentShaderShadingRateInterlockEXT,
+        ShaderSMBuiltinsNV = SpvCapabilityShaderSMBuiltinsNV,
+        FragmentShaderPixelInterlockEXT = SpvCapabilityFragmentShaderPixelInterlockEXT,
+        DemoteToHelperInvocationEXT = SpvCapabilityDemoteToHelperInvocationEXT,
+        SubgroupShuffleINTEL = SpvCapabilitySubgroupShuffleINTEL,
+        SubgroupBufferBlockIOINTEL = SpvCapabilitySubgroupBufferBlockIOINTEL,
+        SubgroupImageBlockIOINTEL = SpvCapabilitySubgroupImageBlockIOINTEL,
+        SubgroupImageMediaBlockIOINTEL = SpvCapabilitySubgroupImageMediaBlockIOINTEL,
+        IntegerFunctions2INTEL = SpvCapabilityIntegerFunctions2INTEL,
+        FunctionPointersINTEL = SpvCapabilityFunctionPointersINTEL,
+        IndirectReferencesINTEL = SpvCapabilityIndirectReferencesINTEL,
+        SubgroupAvcMotionEstimationINTEL = SpvCapabilitySubgroupAvcMotionEstimationINTEL,
+        SubgroupAvcMotionEstimationIntraINTEL = SpvCapabilitySubgroupAvcMotionEstimationIntraINTEL,
+        SubgroupAvcMotionEstimationChromaINTEL = SpvCapabilitySubgroupAvcMotionEstimationChromaINTEL,
+        FPGAMemoryAttributesINTEL = SpvCapabilityFPGAMemoryAttributesINTEL,
+        UnstructuredLoopControlsINTEL = SpvCapabilityUnstructuredLoopControlsINTEL,
+        FPGALoopControlsINTEL = SpvCapabilityFPGALoopControlsINTEL,
+        KernelAttributesINTEL = SpvCapabilityKernelAttributesINTEL,
+        FPGAKernelAttributesINTEL = SpvCapabilityFPGAKernelAttributesINTEL,
+        BlockingPipesINTEL = SpvCapabilityBlockingPipesINTEL,
+        FPGARegINTEL = SpvCapabilityFPGARegINTEL,
+        AtomicFloat32AddEXT = SpvCapabilityAtomicFloat32AddEXT,
+        AtomicFloat64AddEXT = SpvCapabilityAtomicFloat64AddEXT,
+    }
+}
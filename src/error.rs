@@ -1,5 +1,5 @@
 use crate::sys;
-use std::{error::Error as StdError, fmt::Display};
+use std::{error::Error as StdError, ffi::NulError, fmt::Display};
 pub type Result<T, E = Error> = ::std::result::Result<T, E>;
 
 /// A SPIRV-Cross API error, alongside it's error message.
@@ -46,6 +46,15 @@ impl From<sys::spvc_result> for Error {
     }
 }
 
+impl From<NulError> for Error {
+    fn from(value: NulError) -> Self {
+        return Self {
+            code: sys::spvc_result::SPVC_ERROR_INVALID_ARGUMENT,
+            err_msg: Some(value.to_string()),
+        };
+    }
+}
+
 impl StdError for Error {
     #[inline]
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
@@ -24,6 +24,7 @@ pub mod sys;
 
 flat_mod! {
     context,
+    enums,
     error
 }
 
@@ -46,6 +47,8 @@ pub const SPVC_VERSION: Version = Version::new(
 pub fn bytes_to_words(bytes: &[u8]) -> Option<Cow<'_, [u32]>> {
     const SIZE: usize = core::mem::size_of::<u32>();
 
+    // `usize::is_multiple_of` would require Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
     if bytes.len() % SIZE != 0 {
         return None;
     }
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_return)]
+
 use spirvcross::{bytes_to_words, compiler::Compiler, Context, Result};
 
 #[cfg(feature = "glsl")]
@@ -50,3 +52,634 @@ pub fn msl() -> Result<()> {
 
     return Ok(());
 }
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn active_variables() -> Result<()> {
+    use spirvcross::compiler::glsl::GlslCompiler;
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut glsl = GlslCompiler::new(&mut context, &words)?.vulkan_semantics(true)?;
+    glsl.enable_active_interface_variables()?;
+
+    let source = glsl.compile()?;
+    assert!(source.contains("params"));
+    assert!(!source.contains("fetched"));
+    assert!(!source.contains("unused"));
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn combined_image_samplers() -> Result<()> {
+    use spirvcross::{compiler::glsl::GlslCompiler, Decoration};
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut glsl = GlslCompiler::new(&mut context, &words)?.version(4, 5)?;
+    let resources = glsl.shader_resources()?;
+    let image = resources
+        .separate_images()?
+        .into_iter()
+        .find(|image| image.name == "tex")
+        .unwrap();
+    let sampler = resources.separate_samplers()?.remove(0);
+
+    let samplers = glsl.build_combined_image_samplers()?;
+    assert_eq!(samplers.len(), 1);
+    assert_eq!(
+        (samplers[0].image_id, samplers[0].sampler_id),
+        (image.id, sampler.id)
+    );
+
+    glsl.set_name(samplers[0].combined_id, "texSampler")?;
+    glsl.set_decoration(samplers[0].combined_id, Decoration::Binding, 3);
+    assert_eq!(glsl.combined_image_samplers()?, samplers);
+    assert_eq!(
+        glsl.decoration(samplers[0].combined_id, Decoration::Binding),
+        Some(3)
+    );
+
+    let source = glsl.compile()?;
+    println!("{source}");
+    assert!(source.contains("uniform sampler2D texSampler;"));
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn dummy_sampler() -> Result<()> {
+    use spirvcross::{
+        compiler::glsl::{EsOptions, GlslCompiler},
+        Decoration, ExecutionModel,
+    };
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut glsl = GlslCompiler::new(&mut context, &words)?
+        .version(3, 1)?
+        .es(Some(EsOptions::default()))?;
+    assert_eq!(glsl.build_dummy_sampler_for_combined_images()?, None);
+
+    glsl.set_entry_point("fetch_main", ExecutionModel::GLCompute)?;
+    let dummy = glsl.build_dummy_sampler_for_combined_images()?.unwrap();
+
+    let samplers = glsl.build_combined_image_samplers()?;
+    assert_eq!(samplers.len(), 1);
+    assert_eq!(samplers[0].sampler_id, dummy);
+
+    glsl.set_name(samplers[0].combined_id, "fetched")?;
+    glsl.set_decoration(samplers[0].combined_id, Decoration::Binding, 0);
+
+    let source = glsl.compile()?;
+    println!("{source}");
+    assert!(source.contains("texelFetch(fetched"));
+
+    return Ok(());
+}
+
+#[test]
+pub fn parsed_ir() -> Result<()> {
+    use spirvcross::{Backend, ExecutionModel};
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let parsed_ir = context.parse(&words)?;
+
+    // Every compiler works on its own copy of the module, and any number of them can be alive at once
+    let mut renamed = parsed_ir.compiler(Backend::None)?;
+    let original = parsed_ir.compiler(Backend::None)?;
+    renamed.rename_entry_point("main", "vs_main", ExecutionModel::Vertex)?;
+    assert_eq!(renamed.entry_points()?[0].name, "vs_main");
+    assert_eq!(original.entry_points()?[0].name, "main");
+
+    #[cfg(feature = "glsl")]
+    println!(
+        "{}",
+        spirvcross::compiler::GlslCompiler::from_parsed_ir(&parsed_ir)?
+            .raw_compile()?
+            .to_string_lossy()
+    );
+
+    #[cfg(feature = "hlsl")]
+    println!(
+        "{}",
+        spirvcross::compiler::HlslCompiler::from_parsed_ir(&parsed_ir)?.compile()?
+    );
+
+    #[cfg(feature = "msl")]
+    println!(
+        "{}",
+        spirvcross::compiler::MslCompiler::from_parsed_ir(&parsed_ir)?.compile()?
+    );
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn shared_parsed_ir() -> Result<()> {
+    use spirvcross::compiler::{GlslCompiler, ParsedIr};
+    use spirvcross::Backend;
+    use std::rc::Rc;
+
+    let vertex = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+    let compute = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut compilers: Vec<GlslCompiler<'static, Rc<Context>>> = Vec::new();
+    for words in [&vertex, &compute] {
+        let parsed_ir = ParsedIr::new_shared(Context::new()?, words)?;
+        compilers.push(GlslCompiler::from_shared_parsed_ir(&parsed_ir)?.vulkan_semantics(true)?);
+        compilers.push(GlslCompiler::from_shared_parsed_ir(&parsed_ir)?.version(330, 0)?);
+    }
+
+    let vertex_vulkan = compilers[0].compile_mut()?;
+    assert!(vertex_vulkan.contains("gl_Position"));
+    assert_ne!(compilers[1].compile_mut()?, vertex_vulkan);
+    assert!(compilers[2].compile_mut()?.contains("local_size_y = 4"));
+
+    let parsed_ir = ParsedIr::new_shared(Context::new()?, &vertex)?;
+    let generic = parsed_ir.shared_compiler(Backend::Glsl)?;
+    drop(parsed_ir);
+    assert!(generic.compile()?.contains("gl_Position"));
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn recompile() -> Result<()> {
+    use spirvcross::{compiler::glsl::GlslCompiler, CompilerOption, ExecutionModel};
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut glsl = GlslCompiler::new(&mut context, &words)?.vulkan_semantics(true)?;
+
+    let main = glsl.compile_mut()?;
+    assert!(main.contains("textureLod"));
+
+    glsl.set_entry_point("fetch_main", ExecutionModel::GLCompute)?;
+    let fetch_main = glsl.compile_mut()?;
+    assert!(fetch_main.contains("texelFetch"));
+
+    glsl.set_uint_mut(CompilerOption::GlslVersion, 460)?;
+    assert!(glsl.compile_mut()?.starts_with("#version 460"));
+    assert_eq!(glsl.execution_model()?, ExecutionModel::GLCompute);
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn owned() -> Result<()> {
+    use spirvcross::compiler::{glsl::GlslCompiler, GenericCompiler};
+    use spirvcross::Backend;
+
+    struct Cache {
+        compilers: Vec<GlslCompiler<'static, Context>>,
+    }
+
+    let mut cache = Cache {
+        compilers: Vec::new(),
+    };
+    for spv in [
+        &include_bytes!("vertex.spv")[..],
+        &include_bytes!("compute.spv")[..],
+    ] {
+        let words = bytes_to_words(spv).unwrap();
+        let glsl = GlslCompiler::new_owned(Context::new()?, &words)?.vulkan_semantics(true)?;
+        cache.compilers.push(glsl);
+    }
+
+    let vertex = cache.compilers[0].compile_mut()?;
+    let compute = cache.compilers[1].compile_mut()?;
+    assert!(vertex.contains("gl_Position"));
+    assert!(compute.contains("local_size_y = 4"));
+    assert_eq!(cache.compilers.pop().unwrap().compile()?, compute);
+    assert_eq!(cache.compilers.pop().unwrap().compile()?, vertex);
+
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+    let generic = GenericCompiler::new_owned(Context::new()?, Backend::Glsl, &words)?;
+    assert!(generic.compile()?.contains("gl_Position"));
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn send() -> Result<()> {
+    use spirvcross::compiler::glsl::GlslCompiler;
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let glsl = GlslCompiler::new_owned(context, &words)?.vulkan_semantics(true)?;
+    let source = std::thread::spawn(move || glsl.compile()).join().unwrap()?;
+    assert!(source.contains("textureLod"));
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn batch() -> Result<()> {
+    use spirvcross::{
+        compiler::{compile_batch, compile_batch_with_threads, CompileJob},
+        Backend, CompilerOption,
+    };
+    use std::num::NonZeroUsize;
+
+    let vertex = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+    let compute = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+    let invalid = [0u32; 8];
+
+    let jobs = (0..16)
+        .map(|i| match i % 3 {
+            0 => CompileJob::new(&vertex, Backend::Glsl).set_uint(CompilerOption::GlslVersion, 330),
+            1 => CompileJob::new(&compute, Backend::Glsl)
+                .set_bool(CompilerOption::GlslVulkanSemantics, true),
+            _ => CompileJob::new(&invalid, Backend::Glsl),
+        })
+        .collect::<Vec<_>>();
+
+    let results = compile_batch(&jobs);
+    assert_eq!(results.len(), jobs.len());
+    for (i, result) in results.iter().enumerate() {
+        match i % 3 {
+            0 => assert!(result.as_ref().unwrap().starts_with("#version 330")),
+            1 => assert!(result.as_ref().unwrap().contains("local_size_y = 4")),
+            _ => assert!(result.is_err()),
+        }
+    }
+
+    let serial = compile_batch_with_threads(&jobs[..2], NonZeroUsize::MIN);
+    assert_eq!(serial[0].as_ref().unwrap(), results[0].as_ref().unwrap());
+    assert_eq!(serial[1].as_ref().unwrap(), results[1].as_ref().unwrap());
+    assert!(compile_batch(&[]).is_empty());
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn disk_cache() -> Result<()> {
+    use spirvcross::{
+        compiler::{CacheKey, CompileJob, CompileOutput, DiskCache, ReflectedResource, Resource},
+        Backend, CompilerOption, ResourceType,
+    };
+
+    let vertex = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+    let compute = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let dir = std::env::temp_dir().join(format!("spirvcross-cache-{}", std::process::id()));
+    let cache = DiskCache::new(&dir).unwrap();
+    cache.clear().unwrap();
+
+    let job = CompileJob::new(&vertex, Backend::Glsl).set_uint(CompilerOption::GlslVersion, 330);
+    let other_version =
+        CompileJob::new(&vertex, Backend::Glsl).set_uint(CompilerOption::GlslVersion, 450);
+    assert_eq!(CacheKey::new(&job), CacheKey::new(&job.clone()));
+    assert_ne!(CacheKey::new(&job), CacheKey::new(&other_version));
+    assert_ne!(
+        CacheKey::new(&job),
+        CacheKey::new(&CompileJob::new(&vertex, Backend::Glsl))
+    );
+
+    let mut context = Context::new()?;
+    assert!(cache.get(&job).is_none());
+    let output = cache.compile(&job, &mut context)?;
+    assert_eq!(cache.get(&job).as_ref(), Some(&output));
+
+    // Reflection is cached along with the source
+    let mut inputs = output
+        .resources
+        .iter()
+        .filter(|reflected| reflected.ty == ResourceType::StageInput)
+        .map(|reflected| reflected.resource.name.as_str())
+        .collect::<Vec<_>>();
+    inputs.sort();
+    assert_eq!(inputs, ["normal", "position", "texCoord"]);
+
+    // Entries are served from disk, even if they wouldn't compile to the same output
+    let cached = CompileOutput {
+        source: "cached\n".into(),
+        resources: vec![ReflectedResource {
+            ty: ResourceType::UniformBuffer,
+            resource: Resource {
+                id: 1,
+                base_type_id: 2,
+                type_id: 3,
+                name: "block with spaces".into(),
+            },
+            set: Some(0),
+            binding: Some(4),
+            location: None,
+        }],
+    };
+    cache.insert(&other_version, &cached).unwrap();
+    let jobs = [
+        job.clone(),
+        other_version,
+        CompileJob::new(&compute, Backend::Glsl)
+            .set_bool(CompilerOption::GlslVulkanSemantics, true),
+    ];
+    let results = cache.compile_batch(&jobs);
+    assert_eq!(results[0].as_ref().unwrap(), &output);
+    assert_eq!(results[1].as_ref().unwrap(), &cached);
+    assert_eq!(cache.get(&jobs[2]).as_ref(), results[2].as_ref().ok());
+    assert!(results[2]
+        .as_ref()
+        .unwrap()
+        .resources
+        .iter()
+        .any(|reflected| reflected.binding.is_some()));
+
+    // Names that would break the entry's format aren't stored
+    let mut invalid = cached.clone();
+    invalid.resources[0].resource.name = "line\nbreak".into();
+    assert!(cache.insert(&jobs[0], &invalid).is_err());
+    assert_eq!(cache.get(&jobs[0]).as_ref(), Some(&output));
+
+    // Concurrent writes of the same entry don't clobber each other's temporary files
+    std::thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| cache.insert(&job, &output).unwrap());
+        }
+    });
+    assert_eq!(cache.get(&job).as_ref(), Some(&output));
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), jobs.len());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn glsl_es_options() -> Result<()> {
+    use spirvcross::compiler::glsl::{EsOptions, GlslCompiler};
+    // Default precisions are only declared by fragment shaders
+    let words = bytes_to_words(include_bytes!("fragment.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    let mut options = EsOptions::default();
+    options.default_float_precision_highp = true;
+    options.default_int_precision_highp = false;
+
+    let mut glsl = GlslCompiler::new(&mut context, &words)?
+        .version(3, 0)?
+        .es(options)?;
+    glsl.build_combined_image_samplers()?;
+    let source = glsl.compile()?;
+    assert!(source.starts_with("#version 300 es"));
+    assert!(source.contains("precision highp float;"));
+    assert!(source.contains("precision mediump int;"));
+
+    let mut glsl = GlslCompiler::new(&mut context, &words)?
+        .version(3, 0)?
+        .es(EsOptions::default())?;
+    glsl.build_combined_image_samplers()?;
+    let source = glsl.compile()?;
+    assert!(source.contains("precision mediump float;"));
+    assert!(source.contains("precision highp int;"));
+
+    return Ok(());
+}
+
+/// Fails whenever the bindings define a common or GLSL option without a typed setter.
+#[cfg(feature = "glsl")]
+#[test]
+pub fn glsl_option_coverage() {
+    use spirvcross::sys;
+
+    let bindings = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+    let wrappers = [
+        include_str!("../src/compiler.rs"),
+        include_str!("../src/compiler/glsl.rs"),
+    ];
+
+    let start = bindings
+        .find("pub enum spvc_compiler_option {")
+        .expect("bindings define spvc_compiler_option");
+    let body = &bindings[start..];
+    let body = &body[body.find('{').unwrap() + 1..body.find('}').unwrap()];
+
+    let uncovered = body
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line.trim().trim_end_matches(',').split_once(" = ")?;
+            Some((name, value.parse::<u32>().ok()?))
+        })
+        .filter(|(name, value)| {
+            !name.ends_with("_INT_MAX")
+                && value
+                    & (sys::SPVC_COMPILER_OPTION_COMMON_BIT | sys::SPVC_COMPILER_OPTION_GLSL_BIT)
+                    != 0
+        })
+        .filter(|(name, _)| {
+            let variant = name
+                .trim_start_matches("SPVC_COMPILER_OPTION_")
+                .split('_')
+                .map(|word| word[..1].to_owned() + &word[1..].to_lowercase())
+                .collect::<String>();
+            let path = format!("CompilerOption::{variant},");
+            !wrappers.iter().any(|src| src.contains(&path))
+        })
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+
+    assert!(
+        uncovered.is_empty(),
+        "options without a setter: {uncovered:?}"
+    );
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn header_lines() -> Result<()> {
+    use spirvcross::compiler::glsl::GlslCompiler;
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    let mut glsl = GlslCompiler::new(&mut context, &words)?.version(3, 3)?;
+    glsl.require_extension("GL_ARB_separate_shader_objects")?;
+    glsl.require_extension("GL_ARB_separate_shader_objects")?;
+    glsl.add_header_line("#define ENGINE_VERSION 2")?;
+    assert!(glsl.add_header_line("invalid\0line").is_err());
+
+    let source = glsl.compile()?;
+    let lines = source.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "#version 330");
+
+    let extension = lines
+        .iter()
+        .position(|line| *line == "#extension GL_ARB_separate_shader_objects : require")
+        .unwrap();
+    let define = lines
+        .iter()
+        .position(|line| *line == "#define ENGINE_VERSION 2")
+        .unwrap();
+    assert!(extension < define);
+    assert_eq!(source.matches("GL_ARB_separate_shader_objects").count(), 1);
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn flatten_buffer_block() -> Result<()> {
+    use spirvcross::compiler::glsl::{EsOptions, GlslCompiler};
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    let mut glsl = GlslCompiler::new(&mut context, &words)?
+        .version(1, 0)?
+        .es(EsOptions::default())?;
+
+    let blocks = glsl.shader_resources()?.uniform_buffers()?;
+    assert_eq!(blocks.len(), 1);
+
+    // mat4 + std140 mat3 = 64 + 48 bytes
+    let flattened = glsl.flatten_buffer_block(&blocks[0])?;
+    assert_eq!(flattened.id, blocks[0].id);
+    assert_eq!(flattened.name, "Matrices");
+    assert_eq!(flattened.vec4_count, 7);
+
+    let source = glsl.compile()?;
+    assert!(source.contains(&format!(
+        "uniform vec4 {}[{}];",
+        flattened.name, flattened.vec4_count
+    )));
+
+    // Storage buffers can't be flattened, and must be rejected before SPIRV-Cross throws
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+    let mut glsl = GlslCompiler::new(&mut context, &words)?.vulkan_semantics(true)?;
+    let ssbos = glsl.shader_resources()?.storage_buffers()?;
+    assert!(!ssbos.is_empty());
+    for ssbo in ssbos {
+        assert!(glsl.flatten_buffer_block(&ssbo).is_err());
+    }
+    assert!(glsl.compile_mut().is_ok());
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn remap_vulkan_bindings() -> Result<()> {
+    use spirvcross::{
+        compiler::glsl::{BindingClass, GlslCompiler},
+        Decoration,
+    };
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut glsl = GlslCompiler::new(&mut context, &words)?.version(4, 3)?;
+    glsl.enable_active_interface_variables()?;
+    let samplers = glsl.build_combined_image_samplers()?;
+
+    let remaps = glsl.remap_vulkan_bindings()?;
+    let summary = remaps
+        .iter()
+        .map(|remap| (remap.class, remap.set, remap.binding, remap.gl_binding))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (BindingClass::UniformBuffer, 2, 0, 0),
+            (BindingClass::StorageBuffer, 1, 1, 0),
+            (BindingClass::Texture, 0, 0, 0),
+            (BindingClass::Texture, 1, 0, 1),
+            (BindingClass::Texture, 3, 0, 2),
+        ]
+    );
+    assert_eq!(remaps[2].id, samplers[0].combined_id);
+    assert_eq!(glsl.decoration(remaps[0].id, Decoration::Binding), Some(0));
+    assert!(!glsl.has_decoration(remaps[0].id, Decoration::DescriptorSet));
+
+    let source = glsl.compile()?;
+    assert!(source.contains("layout(binding = 0, std140) uniform Params"));
+    assert!(source.contains("layout(binding = 0, std430) buffer Output"));
+    assert!(source.contains("layout(binding = 0) uniform sampler2D"));
+
+    // An image sampled with two samplers yields two combined samplers, which can't share a texture unit
+    let words = bytes_to_words(include_bytes!("fragment.spv")).unwrap();
+    let mut glsl = GlslCompiler::new(&mut context, &words)?.version(4, 3)?;
+    let samplers = glsl.build_combined_image_samplers()?;
+    assert_eq!(samplers.len(), 2);
+    assert_eq!(samplers[0].image_id, samplers[1].image_id);
+
+    let remaps = glsl.remap_vulkan_bindings()?;
+    let summary = remaps
+        .iter()
+        .map(|remap| (remap.class, remap.set, remap.binding, remap.gl_binding))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (BindingClass::Texture, 0, 0, 0),
+            (BindingClass::Texture, 0, 0, 1),
+        ]
+    );
+
+    let source = glsl.compile()?;
+    assert!(source.contains("layout(binding = 0) uniform sampler2D"));
+    assert!(source.contains("layout(binding = 1) uniform sampler2D"));
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn binding_table() -> Result<()> {
+    use spirvcross::compiler::glsl::{BindingClass, GlslCompiler};
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut glsl = GlslCompiler::new(&mut context, &words)?
+        .version(3, 3)?
+        .enable_420_pack_extension(false)?;
+    glsl.enable_active_interface_variables()?;
+    let samplers = glsl.build_combined_image_samplers()?;
+    glsl.set_name(samplers[0].combined_id, "texSampler")?;
+    glsl.remap_vulkan_bindings()?;
+
+    let output = glsl.compile_with_binding_table()?;
+    assert!(!output.source.contains("binding"));
+
+    let table = output
+        .bindings
+        .iter()
+        .map(|entry| (entry.class, entry.name.as_str(), entry.binding))
+        .collect::<Vec<_>>();
+    assert!(table.contains(&(BindingClass::UniformBuffer, "Params", 0)));
+    assert!(table.contains(&(BindingClass::Texture, "texSampler", 0)));
+    assert!(output.source.contains("uniform Params"));
+    assert!(output.source.contains("uniform sampler2D texSampler;"));
+
+    return Ok(());
+}
@@ -0,0 +1,494 @@
+#![allow(clippy::needless_return)]
+
+use spirvcross::{
+    bytes_to_words,
+    compiler::{GenericCompiler, ScalarValue},
+    sys, Backend, BaseType, BuiltIn, Capability, Context, Decoration, ExecutionMode,
+    ExecutionModel, Result,
+};
+
+#[test]
+pub fn resources() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    let resources = compiler.shader_resources()?;
+
+    let uniform_buffers = resources.uniform_buffers()?;
+    assert_eq!(uniform_buffers.len(), 1);
+    assert_eq!(uniform_buffers[0].name, "Matrices");
+
+    let mut inputs = resources
+        .stage_inputs()?
+        .into_iter()
+        .map(|input| input.name)
+        .collect::<Vec<_>>();
+    inputs.sort();
+    assert_eq!(inputs, ["normal", "position", "texCoord"]);
+
+    assert_eq!(resources.stage_outputs()?.len(), 1);
+    assert!(resources.sampled_images()?.is_empty());
+
+    return Ok(());
+}
+
+#[test]
+pub fn builtins() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    let resources = compiler.shader_resources()?;
+
+    assert!(resources.builtin_inputs()?.is_empty());
+    assert!(resources
+        .builtin_outputs()?
+        .iter()
+        .any(|output| output.builtin == BuiltIn::Position));
+
+    return Ok(());
+}
+
+#[test]
+pub fn types() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    let resources = compiler.shader_resources()?;
+    let matrices = &resources.uniform_buffers()?[0];
+
+    let ty = compiler.type_handle(matrices.base_type_id)?;
+    assert_eq!(ty.base_type()?, BaseType::Struct);
+    assert!(ty.array_dimensions().is_empty());
+    assert!(ty.image()?.is_none());
+
+    let members = ty.members()?;
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].base_type()?, BaseType::Fp32);
+    assert_eq!((members[0].vector_size(), members[0].columns()), (4, 4));
+    assert_eq!((members[1].vector_size(), members[1].columns()), (3, 3));
+
+    assert!(compiler.type_handle(10000).is_err());
+    assert!(compiler.type_handle(u32::MAX).is_err());
+
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    let tex = &compiler.shader_resources()?.separate_images()?[0];
+    let image = compiler.type_handle(tex.base_type_id)?.image()?.unwrap();
+    assert_eq!((image.storage, image.access), (false, None));
+
+    return Ok(());
+}
+
+#[test]
+pub fn block_layout() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    let resources = compiler.shader_resources()?;
+    let matrices = &resources.uniform_buffers()?[0];
+
+    let layout = compiler
+        .type_handle(matrices.base_type_id)?
+        .block_layout()?;
+    assert_eq!(layout.size, 112);
+
+    let offsets = layout.members.iter().map(|member| member.offset);
+    assert!(offsets.eq([0, 64]));
+    assert_eq!(layout.members[1].matrix_stride, Some(16));
+    assert_eq!(layout.members[1].array_stride, None);
+
+    let ty = compiler.type_handle(matrices.base_type_id)?;
+    assert!(ty.member_offset(2).is_err());
+    assert!(ty.member_offset(100000).is_err());
+    assert!(ty.declared_member_size(2).is_err());
+    assert!(ty.member_array_stride(2).is_err());
+    assert!(ty.member_matrix_stride(2).is_err());
+
+    return Ok(());
+}
+
+#[test]
+pub fn runtime_array_size() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    let output = compiler.shader_resources()?.storage_buffers()?.remove(0);
+
+    // `Output { vec4 values[]; }`, with an array stride of 16
+    let ty = compiler.type_handle(output.base_type_id)?;
+    assert_eq!(ty.declared_struct_size()?, 0);
+    assert_eq!(ty.declared_struct_size_runtime_array(1)?, 16);
+    assert_eq!(ty.declared_struct_size_runtime_array(10)?, 160);
+
+    return Ok(());
+}
+
+#[test]
+pub fn decorations() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    let matrices = compiler.shader_resources()?.uniform_buffers()?.remove(0);
+
+    compiler.set_decoration(matrices.id, Decoration::DescriptorSet, 2);
+    assert_eq!(
+        compiler.decoration(matrices.id, Decoration::DescriptorSet),
+        Some(2)
+    );
+
+    compiler.unset_decoration(matrices.id, Decoration::DescriptorSet);
+    assert_eq!(
+        compiler.decoration(matrices.id, Decoration::DescriptorSet),
+        None
+    );
+
+    assert_eq!(
+        compiler.member_decoration(matrices.base_type_id, 1, Decoration::Offset),
+        Some(64)
+    );
+
+    compiler.set_decoration_string(matrices.id, Decoration::UserSemantic, "MATRICES")?;
+    assert_eq!(
+        compiler
+            .decoration_string(matrices.id, Decoration::UserSemantic)
+            .as_deref(),
+        Some("MATRICES")
+    );
+
+    return Ok(());
+}
+
+#[test]
+pub fn entry_points() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+
+    let entry_points = compiler.entry_points()?;
+    assert_eq!(entry_points.len(), 1);
+    assert_eq!(entry_points[0].name, "main");
+    assert_eq!(entry_points[0].execution_model, ExecutionModel::Vertex);
+
+    compiler.rename_entry_point("main", "vs_main", ExecutionModel::Vertex)?;
+    compiler.set_entry_point("vs_main", ExecutionModel::Vertex)?;
+    assert_eq!(compiler.entry_points()?[0].name, "vs_main");
+
+    assert!(compiler
+        .set_entry_point("main", ExecutionModel::Fragment)
+        .is_err());
+
+    // GLSL entry points are always emitted as `main`
+    #[cfg(feature = "glsl")]
+    {
+        let mut context = Context::new()?;
+        let mut glsl = GenericCompiler::new(&mut context, Backend::Glsl, &words)?;
+        glsl.rename_entry_point("main", "float", ExecutionModel::Vertex)?;
+        glsl.compile_mut()?;
+        assert_eq!(
+            glsl.cleansed_entry_point_name("float", ExecutionModel::Vertex)?,
+            "main"
+        );
+        assert!(glsl
+            .cleansed_entry_point_name("missing", ExecutionModel::Vertex)
+            .is_err());
+    }
+
+    // `main` and `float` are reserved in MSL
+    #[cfg(feature = "msl")]
+    for (name, cleansed) in [("main", "main0"), ("float", "_float")] {
+        let mut context = Context::new()?;
+        let mut msl = GenericCompiler::new(&mut context, Backend::Msl, &words)?;
+        msl.rename_entry_point("main", name, ExecutionModel::Vertex)?;
+        msl.compile_mut()?;
+        assert_eq!(
+            msl.cleansed_entry_point_name(name, ExecutionModel::Vertex)?,
+            cleansed
+        );
+    }
+
+    return Ok(());
+}
+
+#[test]
+pub fn execution_modes() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    assert_eq!(compiler.execution_model()?, ExecutionModel::GLCompute);
+    assert_eq!(compiler.execution_modes()?, [ExecutionMode::LocalSize]);
+    assert_eq!(
+        compiler.execution_mode_argument(ExecutionMode::LocalSize, 1),
+        4
+    );
+
+    let workgroup_size = compiler.workgroup_size();
+    assert_eq!(workgroup_size.sizes(), [8, 4, 1]);
+    assert_eq!(workgroup_size.x.constant_id, Some(0));
+    assert_eq!(workgroup_size.y.constant_id, None);
+
+    return Ok(());
+}
+
+#[test]
+pub fn specialization_constants() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+
+    let mut constants = compiler.specialization_constants()?;
+    constants.sort_by_key(|constant| constant.constant_id);
+    assert_eq!(constants.len(), 3);
+    assert_eq!(constants[0].value, ScalarValue::U32(8));
+    assert_eq!(constants[1].name, "SCALE");
+    assert_eq!(constants[1].value, ScalarValue::F32(2.0));
+    assert_eq!(constants[2].value, ScalarValue::I32(3));
+
+    compiler.set_specialization_constant(1, ScalarValue::F32(0.5))?;
+    assert!(compiler
+        .specialization_constants()?
+        .iter()
+        .any(|constant| constant.constant_id == 1 && constant.value == ScalarValue::F32(0.5)));
+
+    assert!(compiler
+        .set_specialization_constant(2, ScalarValue::F32(1.0))
+        .is_err());
+    assert!(compiler
+        .set_specialization_constant(7, ScalarValue::U32(1))
+        .is_err());
+
+    return Ok(());
+}
+
+#[test]
+pub fn unsupported_specialization_constants() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("spec64.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+
+    let mut constants = compiler.specialization_constants()?;
+    constants.sort_by_key(|constant| constant.constant_id);
+    assert_eq!(constants.len(), 2);
+    assert!(matches!(
+        constants[0].value,
+        ScalarValue::Unsupported { .. }
+    ));
+    assert_eq!(constants[1].value, ScalarValue::I32(7));
+
+    assert!(compiler
+        .set_specialization_constant(0, ScalarValue::I32(1))
+        .is_err());
+    compiler.set_specialization_constant(1, ScalarValue::I32(3))?;
+    assert_eq!(
+        compiler.specialization_constants()?[1].value,
+        ScalarValue::I32(3)
+    );
+
+    return Ok(());
+}
+
+#[test]
+pub fn capabilities() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    assert_eq!(compiler.declared_capabilities()?, [Capability::Shader]);
+    assert!(compiler.declared_extensions()?.is_empty());
+
+    let words = bytes_to_words(include_bytes!("spec64.spv")).unwrap();
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    assert_eq!(
+        compiler.declared_capabilities()?,
+        [Capability::Shader, Capability::Int64]
+    );
+
+    return Ok(());
+}
+
+#[test]
+pub fn active_resources() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("compute.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+
+    let separate_images = |resources: spirvcross::compiler::ShaderResources| -> Result<_> {
+        let mut names = resources
+            .separate_images()?
+            .into_iter()
+            .map(|image| image.name)
+            .collect::<Vec<_>>();
+        names.sort();
+        return Ok(names);
+    };
+
+    assert_eq!(
+        separate_images(compiler.shader_resources()?)?,
+        ["fetched", "tex", "unused"]
+    );
+    assert_eq!(
+        separate_images(compiler.active_shader_resources()?)?,
+        ["tex"]
+    );
+
+    return Ok(());
+}
+
+#[test]
+pub fn names() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("vertex.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    let matrices = compiler.shader_resources()?.uniform_buffers()?.remove(0);
+
+    assert_eq!(compiler.name_of(matrices.base_type_id), "Matrices");
+    assert_eq!(
+        compiler.member_name_of(matrices.base_type_id, 1),
+        "normalMatrix"
+    );
+
+    compiler.set_name(matrices.base_type_id, "Transforms")?;
+    compiler.set_member_name(matrices.base_type_id, 1, "normalTransform")?;
+    assert_eq!(compiler.name_of(matrices.base_type_id), "Transforms");
+    assert_eq!(
+        compiler.member_name_of(matrices.base_type_id, 1),
+        "normalTransform"
+    );
+    assert_eq!(
+        compiler.remapped_declared_block_name(matrices.id)?,
+        "Transforms"
+    );
+    assert!(compiler
+        .remapped_declared_block_name(matrices.base_type_id)
+        .is_err());
+    assert!(compiler.remapped_declared_block_name(10000).is_err());
+    assert!(compiler.remapped_declared_block_name(u32::MAX).is_err());
+
+    return Ok(());
+}
+
+#[test]
+pub fn enum_conversions() -> Result<()> {
+    assert_eq!(
+        Decoration::try_from(sys::SpvDecoration::SpvDecorationBinding)?,
+        Decoration::Binding
+    );
+    assert_eq!(
+        sys::SpvExecutionModel::from(ExecutionModel::GLCompute),
+        sys::SpvExecutionModel::SpvExecutionModelGLCompute
+    );
+    assert!(Backend::try_from(sys::spvc_backend::SPVC_BACKEND_INT_MAX).is_err());
+
+    // Raw values, which may be newer than the bundled headers
+    assert_eq!(Capability::try_from(1u32)?, Capability::Shader);
+    assert!(Capability::try_from(0x7ff00000u32).is_err());
+    assert!(BuiltIn::try_from(u32::MAX).is_err());
+
+    return Ok(());
+}
+
+#[test]
+pub fn unknown_enum_values() -> Result<()> {
+    let words = bytes_to_words(include_bytes!("unknown.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    // Values unknown to the bundled headers are skipped, instead of failing the whole list
+    let compiler = GenericCompiler::new(&mut context, Backend::None, &words)?;
+    assert_eq!(compiler.declared_capabilities()?, [Capability::Shader]);
+    assert_eq!(compiler.execution_modes()?, [ExecutionMode::LocalSize]);
+    assert_eq!(compiler.entry_points()?.len(), 1);
+
+    return Ok(());
+}
+
+#[cfg(feature = "glsl")]
+#[test]
+pub fn remap_arrayed_bindings() -> Result<()> {
+    use spirvcross::compiler::glsl::{BindingClass, GlslCompiler};
+    let words = bytes_to_words(include_bytes!("arrays.spv")).unwrap();
+
+    let mut context = Context::new()?;
+    #[cfg(feature = "nightly")]
+    context.set_error_callback(|err| eprintln!("{}", err.to_string_lossy()));
+
+    let mut glsl = GlslCompiler::new(&mut context, &words)?;
+    let remaps = glsl.remap_vulkan_bindings()?;
+    let summary = remaps
+        .iter()
+        .map(|remap| (remap.class, remap.set, remap.binding, remap.gl_binding))
+        .collect::<Vec<_>>();
+
+    // `textures[4]` takes texture units 0 to 3 and `lights[2]` uniform buffer bindings 0 and 1
+    assert_eq!(
+        summary,
+        [
+            (BindingClass::UniformBuffer, 1, 0, 0),
+            (BindingClass::UniformBuffer, 1, 1, 2),
+            (BindingClass::Texture, 0, 0, 0),
+            (BindingClass::Texture, 0, 1, 4),
+        ]
+    );
+
+    let source = glsl.compile_mut()?;
+    assert!(source.contains("layout(binding = 0) uniform sampler2D textures[4];"));
+    assert!(source.contains("layout(binding = 4) uniform sampler2D shadow;"));
+
+    let words = bytes_to_words(include_bytes!("runtime_arrays.spv")).unwrap();
+    let mut glsl = GlslCompiler::new(&mut context, &words)?;
+    assert!(glsl.remap_vulkan_bindings().is_err());
+
+    return Ok(());
+}